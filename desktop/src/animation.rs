@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+/// Longest step animations advance in one frame, so a window that stops
+/// drawing for a while resumes where it left off instead of skipping ahead
+const MAX_FRAME_STEP: Duration = Duration::from_millis(100);
+
+/// Source of the current time for animations
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The clock used by the running app
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Measures how far animations should advance on each frame
+///
+/// Animations only move forward by the steps this returns, so giving it a
+/// clock that tests control makes every frame reproducible.
+pub struct Animator {
+    clock: Box<dyn Clock>,
+    last_frame: Option<Instant>,
+}
+
+impl Animator {
+    pub fn new(clock: Box<dyn Clock>) -> Self {
+        Animator {
+            clock,
+            last_frame: None,
+        }
+    }
+
+    /// Returns the seconds since the previous frame; the first frame after
+    /// `restart` is zero
+    pub fn frame(&mut self) -> f32 {
+        let now = self.clock.now();
+        let step = self.last_frame.map_or(Duration::ZERO, |last| {
+            now.saturating_duration_since(last).min(MAX_FRAME_STEP)
+        });
+        self.last_frame = Some(now);
+        step.as_secs_f32()
+    }
+
+    /// Forgets the previous frame, so an animation starting after an idle
+    /// period doesn't begin with a large step
+    pub fn restart(&mut self) {
+        self.last_frame = None;
+    }
+}
+
+/// A clock that only moves when a test advances it
+#[cfg(test)]
+#[derive(Clone)]
+pub(crate) struct ManualClock {
+    now: std::rc::Rc<std::cell::Cell<Instant>>,
+}
+
+#[cfg(test)]
+impl ManualClock {
+    pub(crate) fn new() -> Self {
+        ManualClock {
+            now: std::rc::Rc::new(std::cell::Cell::new(Instant::now())),
+        }
+    }
+
+    pub(crate) fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+#[cfg(test)]
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_frame_does_not_advance() {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(animator.frame(), 0.0);
+    }
+
+    #[test]
+    fn frames_advance_by_elapsed_time() {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        animator.frame();
+        clock.advance(Duration::from_millis(16));
+        assert!((animator.frame() - 0.016).abs() < 1e-6);
+        clock.advance(Duration::from_millis(33));
+        assert!((animator.frame() - 0.033).abs() < 1e-6);
+    }
+
+    #[test]
+    fn long_gaps_are_capped() {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        animator.frame();
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(animator.frame(), MAX_FRAME_STEP.as_secs_f32());
+    }
+
+    #[test]
+    fn restart_forgets_previous_frame() {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        animator.frame();
+        clock.advance(Duration::from_millis(50));
+        animator.restart();
+        assert_eq!(animator.frame(), 0.0);
+    }
+}
@@ -1,25 +1,37 @@
-use crate::celebration::Celebration;
-use crate::config::ColorPalette;
+use crate::animation::{Animator, SystemClock};
+use crate::audio::{AudioEngine, Channel};
+use crate::celebration::{Celebration, CelebrationStyle, ParticleShape};
+use crate::config::{ColorPalette, ConfigService, LetterCase, PhonicsMode};
 use crate::dictionary::Dictionary;
-use crate::discovered_word::DiscoveredWord;
+use crate::discovered_word::{DiscoveredWord, WordSuggestion};
 use crate::grade_level::GradeLevel;
 use crate::kiosk_mode::{KioskMode, KioskModeStatus};
 use crate::letter::Letter;
 use crate::message::Message;
+use crate::music::{self, MusicLibrary};
+use crate::phonics;
+use crate::profile::Profile;
+use crate::progress::ProgressReport;
 use crate::session::Session;
+use crate::sound_scheme::SoundEvent;
+use crate::speech::{Speaker, VoicePack, DEFAULT_VOICE_PACK};
+use crate::system_sound::SoundLibrary;
 use crate::tic_tac_toe::TicTacToe;
 use crate::utils::color::hsl_to_rgb;
-use crate::word_challenge::{ChallengeMode, WordChallenge};
+use crate::voice_recording::VoiceRecording;
+use crate::word_challenge::{AdaptiveState, ChallengeMode, WordChallenge};
 use crate::word_list_loader::WordListLoader;
 use iced::{
     alignment, event, exit, keyboard, mouse,
     widget::{
         button, canvas, column, container, row, scrollable, scrollable::Id as ScrollableId, stack,
-        text, Row,
+        text, text_input, Row,
     },
-    window, Color, Element, Event, Length, Point, Rectangle, Renderer, Subscription, Task, Theme,
+    window, Color, Element, Event, Length, Point, Rectangle, Renderer, Size, Subscription, Task,
+    Theme, Vector,
 };
-use std::sync::{atomic::AtomicBool, Arc};
+use std::sync::Arc;
+use std::time::Instant;
 
 /// Represents the different screens in the application
 #[derive(Debug, Clone, PartialEq)]
@@ -29,65 +41,108 @@ pub enum Screen {
     Settings,
     WordChallenge,
     TicTacToe,
+    Progress,
+    Recording,
 }
 
 /// Main application state for Words with Toddlers
 pub struct WordsWithToddlers {
     letters: Vec<Letter>,
     discovered_words: Vec<DiscoveredWord>,
-    dictionary: Dictionary,
+    suggestions: Vec<WordSuggestion>,
+    dictionary: Arc<Dictionary>,
+    /// Counts dictionary reloads, so a slow load finishing after a newer one
+    /// started is ignored
+    dictionary_generation: u64,
     is_fullscreen: bool,
     letters_scroll_id: ScrollableId,
     has_started_typing: bool,
     cursor_visible: bool,
-    sound_playing: Arc<AtomicBool>,
+    audio: AudioEngine,
+    sounds: SoundLibrary,
+    music: MusicLibrary,
+    editing_sound_event: SoundEvent,
     current_screen: Screen,
-    selected_sound: String,
-    use_uppercase: bool,
+    config: ConfigService,
+    speaker: Speaker,
     word_list_loader: WordListLoader,
     word_challenge: Option<WordChallenge>,
     celebration: Option<Celebration>,
+    animator: Animator,
     tic_tac_toe: Option<TicTacToe>,
     kiosk_mode: Option<KioskMode>,
-    kiosk_mode_enabled: bool,
-    color_palette: ColorPalette,
+    discovery_started_at: Option<Instant>,
+    progress_report: Option<ProgressReport>,
+    new_profile_name: String,
+    /// Word being typed into the Settings block/allow field
+    custom_word: String,
+    voice_recording: Option<VoiceRecording>,
 }
 
 impl WordsWithToddlers {
     /// Creates a new instance of the application
     pub fn new() -> (Self, Task<Message>) {
         // Load saved configuration
-        let config = crate::config::load_config();
+        let config = ConfigService::load();
+        let audio = AudioEngine::start(&config.get().audio);
+        let sounds = SoundLibrary::load();
+        audio.preload(sounds.all().iter().map(|choice| choice.sound.clone()));
+        let speaker = Speaker::detect(&config.get().speech, audio.clone());
+        let music = MusicLibrary::load();
+        if let Some(track) = config
+            .get()
+            .audio
+            .music_track
+            .as_deref()
+            .and_then(|id| music.get(id))
+        {
+            audio.play_music(track.sound.clone());
+        }
+
+        let mut app = WordsWithToddlers {
+            letters: Vec::new(),
+            discovered_words: Vec::new(),
+            suggestions: Vec::new(),
+            dictionary: Arc::new(Dictionary::default()),
+            dictionary_generation: 0,
+            is_fullscreen: false,
+            letters_scroll_id: ScrollableId::unique(),
+            has_started_typing: false,
+            cursor_visible: true,
+            audio,
+            sounds,
+            music,
+            editing_sound_event: SoundEvent::WordDiscovered,
+            current_screen: Screen::Settings,
+            config,
+            speaker,
+            word_list_loader: WordListLoader::new(),
+            word_challenge: None,
+            celebration: None,
+            animator: Animator::new(Box::new(SystemClock)),
+            tic_tac_toe: None,
+            kiosk_mode: None,
+            discovery_started_at: None,
+            progress_report: None,
+            new_profile_name: String::new(),
+            custom_word: String::new(),
+            voice_recording: None,
+        };
+        let load_dictionary = app.reload_dictionary();
 
         (
-            WordsWithToddlers {
-                letters: Vec::new(),
-                discovered_words: Vec::new(),
-                dictionary: Dictionary::new(),
-                is_fullscreen: false,
-                letters_scroll_id: ScrollableId::unique(),
-                has_started_typing: false,
-                cursor_visible: true,
-                sound_playing: Arc::new(AtomicBool::new(false)),
-                current_screen: Screen::Settings,
-                selected_sound: config.selected_sound,
-                use_uppercase: config.use_uppercase,
-                word_list_loader: WordListLoader::new(),
-                word_challenge: None,
-                celebration: None,
-                tic_tac_toe: None,
-                kiosk_mode: None,
-                kiosk_mode_enabled: config.kiosk_mode_enabled,
-                color_palette: config.color_palette,
-            },
-            // Send a message after a short delay to set window to AlwaysOnTop
-            Task::perform(
-                async {
-                    // Small delay to ensure window is created in current Space
-                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                },
-                |_| Message::WindowOpened,
-            ),
+            app,
+            Task::batch([
+                // Send a message after a short delay to set window to AlwaysOnTop
+                Task::perform(
+                    async {
+                        // Small delay to ensure window is created in current Space
+                        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    },
+                    |_| Message::WindowOpened,
+                ),
+                load_dictionary,
+            ]),
         )
     }
 
@@ -105,98 +160,141 @@ impl WordsWithToddlers {
                 Task::none()
             }
             Message::NavigateToSettings => {
-                self.current_screen = Screen::Settings;
+                self.show_screen(Screen::Settings);
+                Task::none()
+            }
+            Message::NavigateToProgress => {
+                // Session history is read off the UI thread; the screen shows
+                // a loading message until it arrives
+                self.progress_report = None;
+                self.show_screen(Screen::Progress);
+                let profile = self.active_profile().clone();
+                let profile_id = profile.id.clone();
+                Task::perform(ProgressReport::load_in_background(profile), move |report| {
+                    Message::ProgressLoaded(profile_id.clone(), report)
+                })
+            }
+            Message::NavigateToRecording => {
+                let pack_id = self
+                    .config
+                    .get()
+                    .speech
+                    .voice_pack
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_VOICE_PACK.to_string());
+                let grade = self.active_profile().starting_grade;
+
+                match VoicePack::open_or_create(&pack_id) {
+                    Ok(pack) => {
+                        let words = self
+                            .word_list_loader
+                            .get_words_for_grade(grade)
+                            .cloned()
+                            .unwrap_or_default();
+                        self.voice_recording = Some(VoiceRecording::new(pack, grade, words));
+                        self.show_screen(Screen::Recording);
+                    }
+                    Err(e) => eprintln!("Failed to open voice pack {:?}: {}", pack_id, e),
+                }
                 Task::none()
             }
             Message::NavigateToWelcome => {
-                self.current_screen = Screen::Welcome;
+                self.show_screen(Screen::Welcome);
                 self.word_challenge = None;
                 self.celebration = None;
                 Task::none()
             }
             Message::NavigateToMain => {
-                self.current_screen = Screen::Main;
+                self.show_screen(Screen::Main);
                 Task::none()
             }
             Message::StartVisualChallenge => {
-                if let Some(words) = self.word_list_loader.get_words_for_grade(GradeLevel::PreK) {
-                    self.word_challenge =
-                        Some(WordChallenge::new(ChallengeMode::Visual, words.clone()));
-                    self.current_screen = Screen::WordChallenge;
-
-                    // Speak the first word
-                    if let Some(ref challenge) = self.word_challenge {
-                        crate::speech::speak_word_async(challenge.current_word.clone());
-                    }
-                }
+                self.start_challenge(ChallengeMode::Visual);
                 Task::none()
             }
             Message::StartAudioChallenge => {
-                if let Some(words) = self.word_list_loader.get_words_for_grade(GradeLevel::PreK) {
-                    self.word_challenge =
-                        Some(WordChallenge::new(ChallengeMode::Audio, words.clone()));
-                    self.current_screen = Screen::WordChallenge;
-
-                    // Speak the first word
-                    if let Some(ref challenge) = self.word_challenge {
-                        crate::speech::speak_word_async(challenge.current_word.clone());
-                    }
-                }
+                self.start_challenge(ChallengeMode::Audio);
                 Task::none()
             }
             Message::ReplayWord => {
                 if let Some(ref challenge) = self.word_challenge {
-                    crate::speech::speak_word_async(challenge.current_word.clone());
+                    self.speak(challenge.current_word.clone());
                 }
                 Task::none()
             }
             Message::CheckTypedWord => {
+                let style = self.active_profile().celebration_style;
+                let palette = self.active_profile().color_palette.clone();
                 if let Some(ref mut challenge) = self.word_challenge {
                     if challenge.check_if_correct() {
                         challenge.handle_correct_word();
-                        self.celebration = Some(Celebration::new());
-
-                        // Play success sound with fresh flag
-                        self.sound_playing
-                            .store(false, std::sync::atomic::Ordering::Relaxed);
-                        let sound_path = crate::system_sound::get_sound_path(&self.selected_sound);
-                        crate::audio::play_sound(
-                            self.sound_playing.clone(),
-                            sound_path.to_string(),
-                        );
+                        let celebration =
+                            Celebration::new(style, challenge.streak, &palette, rand::random());
+                        self.start_animation();
+                        self.celebration = Some(celebration);
 
-                        // Schedule celebration finish
-                        return Task::perform(
-                            async {
-                                tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
-                            },
-                            |_| Message::FinishCelebration,
-                        );
+                        self.play_event(SoundEvent::WordCorrect);
+
+                        self.save_challenge_state();
                     } else {
                         challenge.handle_incorrect_word();
+                        self.play_event(SoundEvent::WordWrong);
+                        self.save_challenge_state();
                     }
                 }
                 Task::none()
             }
+            Message::AnimationFrame => {
+                let dt = self.animator.frame();
+                if let Some(ref mut celebration) = self.celebration {
+                    celebration.update(dt);
+                }
+                for letter in &mut self.letters {
+                    letter.update(dt);
+                }
+                let mut word_landed = false;
+                for word in &mut self.discovered_words {
+                    let was_flying = word.is_flying();
+                    word.update(dt);
+                    word_landed |= was_flying && !word.is_flying();
+                }
+                if word_landed {
+                    self.play_event(SoundEvent::WordLanded);
+                }
+
+                // The challenge moves on once the celebration has played out
+                if self.celebration.as_ref().is_some_and(|c| !c.is_active()) {
+                    self.celebration = None;
+                    return Task::done(Message::FinishCelebration);
+                }
+                Task::none()
+            }
             Message::FinishCelebration => {
+                let mut level_event = None;
                 if let Some(ref mut challenge) = self.word_challenge {
                     // Check if we should level up or down
                     if challenge.should_level_up() {
                         if let Some(new_level) = challenge.level_up() {
+                            level_event = Some(SoundEvent::LevelUp);
                             if let Some(words) =
                                 self.word_list_loader.get_words_for_grade(new_level)
                             {
                                 challenge.update_word_list(words.clone());
+                                self.speaker
+                                    .prerender(words.clone(), self.config.get().speech.clone());
                             }
                         }
                         // Still need to clear celebration state
                         challenge.is_celebrating = false;
                     } else if challenge.should_level_down() {
                         if let Some(new_level) = challenge.level_down() {
+                            level_event = Some(SoundEvent::LevelDown);
                             if let Some(words) =
                                 self.word_list_loader.get_words_for_grade(new_level)
                             {
                                 challenge.update_word_list(words.clone());
+                                self.speaker
+                                    .prerender(words.clone(), self.config.get().speech.clone());
                             }
                         }
                         // Still need to clear celebration state
@@ -204,96 +302,129 @@ impl WordsWithToddlers {
                     } else {
                         challenge.finish_celebration();
                     }
+                }
+
+                if let Some(event) = level_event {
+                    self.play_event(event);
+                }
 
-                    // Speak the new word for both visual and audio modes
-                    crate::speech::speak_word_async(challenge.current_word.clone());
+                // Speak the new word for both visual and audio modes
+                if let Some(ref challenge) = self.word_challenge {
+                    self.speak(challenge.current_word.clone());
                 }
                 self.celebration = None;
+                self.save_challenge_state();
                 Task::none()
             }
             Message::ExitChallenge => {
                 // Save challenge session
                 if let Some(ref challenge) = self.word_challenge {
-                    let session = Session::new_challenge(
+                    let mut session = Session::new_challenge(
                         challenge.grade_level,
                         challenge.score,
-                        challenge.words_completed,
+                        challenge.attempts.clone(),
                     );
-                    if let Err(e) = session.save() {
+                    session.duration_seconds = Some(challenge.started_at.elapsed().as_secs());
+                    if let Err(e) = self
+                        .active_profile()
+                        .sessions_dir()
+                        .and_then(|dir| session.save(&dir))
+                    {
                         eprintln!("Failed to save challenge session: {}", e);
                     }
                 }
+                self.speaker.stop();
                 self.word_challenge = None;
                 self.celebration = None;
-                self.current_screen = Screen::Welcome;
+                self.show_screen(Screen::Welcome);
                 Task::none()
             }
-            Message::SelectSound(sound_name) => {
-                self.selected_sound = sound_name.clone();
-
+            Message::SelectSoundEvent(event) => {
+                self.editing_sound_event = event;
+                Task::none()
+            }
+            Message::SelectSound(sound_id) => {
                 // Play the newly selected sound
-                let sound_path = crate::system_sound::get_sound_path(&sound_name);
-                crate::audio::play_sound(self.sound_playing.clone(), sound_path.to_string());
-
-                // Save configuration
-                let config = crate::config::AppConfig {
-                    selected_sound: sound_name,
-                    last_selected_grade: GradeLevel::default(),
-                    use_uppercase: self.use_uppercase,
-                    kiosk_mode_enabled: self.kiosk_mode_enabled,
-                    color_palette: self.color_palette.clone(),
-                };
-                if let Err(e) = crate::config::save_config(&config) {
-                    eprintln!("Failed to save config: {}", e);
+                if let Some(ref id) = sound_id {
+                    self.audio.play_effect(self.sounds.get(id));
                 }
+
+                let event = self.editing_sound_event;
+                self.config
+                    .update_active_profile(|profile| profile.sound_scheme.set(event, sound_id));
                 Task::none()
             }
 
-            Message::ToggleUppercase(value) => {
-                self.use_uppercase = value;
-
-                // Save configuration
-                let config = crate::config::AppConfig {
-                    selected_sound: self.selected_sound.clone(),
-                    last_selected_grade: GradeLevel::default(),
-                    use_uppercase: value,
-                    kiosk_mode_enabled: self.kiosk_mode_enabled,
-                    color_palette: self.color_palette.clone(),
-                };
-                if let Err(e) = crate::config::save_config(&config) {
-                    eprintln!("Failed to save config: {}", e);
+            Message::SelectMusicTrack(track_id) => {
+                match track_id.as_deref().and_then(|id| self.music.get(id)) {
+                    Some(track) => self.audio.play_music(track.sound.clone()),
+                    None => self.audio.stop(Channel::Music),
                 }
+                self.config
+                    .update(|config| config.audio.music_track = track_id);
+                Task::none()
+            }
+            Message::SelectMusicVolume(volume) => {
+                self.audio.set_volume(Channel::Music, volume);
+                self.config
+                    .update(|config| config.audio.music_volume = volume);
+                Task::none()
+            }
+            Message::WindowFocusChanged(focused) => {
+                // Music pauses while the app is in the background
+                self.audio.pause_music(!focused);
+                Task::none()
+            }
+            Message::SelectPhonicsMode(mode) => {
+                self.config
+                    .update_active_profile(|profile| profile.phonics = mode);
+                Task::none()
+            }
+            Message::SelectCelebrationStyle(style) => {
+                self.config
+                    .update_active_profile(|profile| profile.celebration_style = style);
+                Task::none()
+            }
+            Message::SelectLetterCase(letter_case) => {
+                self.config
+                    .update_active_profile(|profile| profile.letter_case = letter_case);
                 Task::none()
             }
 
             Message::StartTicTacToe => {
                 self.tic_tac_toe = Some(TicTacToe::new(crate::tic_tac_toe::GameMode::TwoPlayer));
-                self.current_screen = Screen::TicTacToe;
+                self.show_screen(Screen::TicTacToe);
                 Task::none()
             }
             Message::StartTicTacToeOnePlayer => {
                 self.tic_tac_toe = Some(TicTacToe::new(crate::tic_tac_toe::GameMode::OnePlayer));
-                self.current_screen = Screen::TicTacToe;
+                self.show_screen(Screen::TicTacToe);
                 Task::none()
             }
             Message::StartTicTacToeTwoPlayer => {
                 self.tic_tac_toe = Some(TicTacToe::new(crate::tic_tac_toe::GameMode::TwoPlayer));
-                self.current_screen = Screen::TicTacToe;
+                self.show_screen(Screen::TicTacToe);
                 Task::none()
             }
             Message::TicTacToeMove(position) => {
+                let mut moved = false;
                 if let Some(ref mut game) = self.tic_tac_toe {
-                    game.make_move(position);
+                    moved = game.make_move(position);
                     if game.is_computer_turn() {
                         return Task::done(Message::TicTacToeComputerMove);
                     }
                 }
+                // Taps on a finished board don't replay the result sound
+                if moved {
+                    self.play_game_result();
+                }
                 Task::none()
             }
             Message::TicTacToeComputerMove => {
                 if let Some(ref mut game) = self.tic_tac_toe {
                     game.computer_move();
                 }
+                self.play_game_result();
                 Task::none()
             }
             Message::ResetTicTacToe => {
@@ -304,7 +435,7 @@ impl WordsWithToddlers {
             }
             Message::ExitTicTacToe => {
                 self.tic_tac_toe = None;
-                self.current_screen = Screen::Welcome;
+                self.show_screen(Screen::Welcome);
                 Task::none()
             }
             Message::ToggleKioskMode(enable) => {
@@ -323,7 +454,6 @@ impl WordsWithToddlers {
                         match KioskMode::start() {
                             Ok(kiosk) => {
                                 self.kiosk_mode = Some(kiosk);
-                                self.kiosk_mode_enabled = true;
                                 self.save_kiosk_config(true);
                             }
                             Err(e) => {
@@ -337,7 +467,6 @@ impl WordsWithToddlers {
                         if let Some(mut kiosk) = self.kiosk_mode.take() {
                             kiosk.stop();
                         }
-                        self.kiosk_mode_enabled = false;
                         self.save_kiosk_config(false);
                     }
                 }
@@ -373,37 +502,304 @@ impl WordsWithToddlers {
                 Task::none()
             }
             Message::SelectColorPalette(palette) => {
-                self.color_palette = palette.clone();
-
-                let config = crate::config::AppConfig {
-                    selected_sound: self.selected_sound.clone(),
-                    last_selected_grade: GradeLevel::default(),
-                    use_uppercase: self.use_uppercase,
-                    kiosk_mode_enabled: self.kiosk_mode_enabled,
-                    color_palette: palette,
+                self.config
+                    .update_active_profile(|profile| profile.color_palette = palette);
+                Task::none()
+            }
+            Message::SelectProfile(id) => {
+                if self.config.get().profiles.iter().any(|p| p.id == id) {
+                    self.config
+                        .update(|config| config.active_profile = Some(id));
+                }
+                Task::none()
+            }
+            Message::SelectSystemDictionary(enabled) => {
+                self.config
+                    .update(|config| config.dictionary.use_system_dictionary = enabled);
+                self.reload_dictionary()
+            }
+            Message::SelectMaxWordRank(max_rank) => {
+                self.config
+                    .update(|config| config.dictionary.max_word_rank = max_rank);
+                self.reload_dictionary()
+            }
+            Message::CustomWordChanged(word) => {
+                self.custom_word = word;
+                Task::none()
+            }
+            Message::BlockCustomWord => self.add_custom_word(true),
+            Message::AllowCustomWord => self.add_custom_word(false),
+            Message::RemoveCustomWord(word) => {
+                self.config.update(|config| {
+                    config.dictionary.blocked_words.remove(&word);
+                    config.dictionary.allowed_words.remove(&word);
+                });
+                self.reload_dictionary()
+            }
+            Message::DictionaryLoaded(generation, dictionary) => {
+                match dictionary {
+                    Some(dictionary) if generation == self.dictionary_generation => {
+                        self.dictionary = dictionary;
+                    }
+                    Some(_) => {}
+                    None => eprintln!("Failed to load the dictionary; using toddler words only"),
+                }
+                Task::none()
+            }
+            Message::ProgressLoaded(profile_id, report) => {
+                if self.current_screen == Screen::Progress && profile_id == self.active_profile().id
+                {
+                    if report.is_none() {
+                        eprintln!("Failed to load the progress report");
+                    }
+                    self.progress_report = Some(report.unwrap_or_default());
+                }
+                Task::none()
+            }
+            Message::NewProfileNameChanged(name) => {
+                self.new_profile_name = name;
+                Task::none()
+            }
+            Message::AddProfile => {
+                let name = self.new_profile_name.trim().to_string();
+                if name.is_empty() {
+                    return Task::none();
+                }
+
+                // New profiles start from the current profile's settings
+                self.new_profile_name.clear();
+                self.config.update(|config| {
+                    let profile = Profile::new(&name, &config.profiles, config.active_profile());
+                    config.active_profile = Some(profile.id.clone());
+                    config.profiles.push(profile);
+                });
+                Task::none()
+            }
+            Message::RemoveProfile(id) => {
+                // Always keep at least one profile; saved sessions stay on disk
+                if self.config.get().profiles.len() > 1 {
+                    // Removing the active profile falls back to the first remaining one
+                    self.config
+                        .update(|config| config.profiles.retain(|p| p.id != id));
+                }
+                Task::none()
+            }
+            Message::SelectStartingGrade(grade) => {
+                // Choosing a grade restarts the adaptive challenge from it
+                if let Err(e) = self
+                    .active_profile()
+                    .save_adaptive_state(&AdaptiveState::starting_at(grade))
+                {
+                    eprintln!("Failed to save challenge state: {}", e);
+                }
+                self.config
+                    .update(|config| config.active_profile_mut().starting_grade = grade);
+                Task::none()
+            }
+            Message::DismissConfigError => {
+                self.config.dismiss_error();
+                Task::none()
+            }
+            Message::SelectRecordingGrade(grade) => {
+                if let Some(ref mut recording) = self.voice_recording {
+                    let words = self
+                        .word_list_loader
+                        .get_words_for_grade(grade)
+                        .cloned()
+                        .unwrap_or_default();
+                    recording.set_words(grade, words);
+                }
+                Task::none()
+            }
+            Message::ToggleRecording => {
+                let Some(ref mut recording) = self.voice_recording else {
+                    return Task::none();
                 };
-                if let Err(e) = crate::config::save_config(&config) {
-                    eprintln!("Failed to save config: {}", e);
+
+                if !recording.is_recording() {
+                    self.speaker.stop();
+                    recording.start_recording();
+                    return Task::none();
+                }
+
+                if recording.stop_recording() {
+                    self.speaker.reload_voice_pack();
+                    // Use the pack as soon as it has a clip, unless another pack is chosen
+                    if self.config.get().speech.voice_pack.is_none() {
+                        self.config.update(|config| {
+                            config.speech.voice_pack = Some(DEFAULT_VOICE_PACK.to_string())
+                        });
+                    }
+                }
+                Task::none()
+            }
+            Message::PlayRecordedClip => {
+                if let Some(ref recording) = self.voice_recording {
+                    if let Some(path) = recording
+                        .current_word()
+                        .and_then(|word| recording.pack.clip_for(word))
+                    {
+                        self.speaker
+                            .play_clip(path, self.config.get().speech.volume);
+                    }
                 }
                 Task::none()
             }
+            Message::NextRecordingWord => {
+                if let Some(ref mut recording) = self.voice_recording {
+                    recording.next_word();
+                }
+                Task::none()
+            }
+            Message::PreviousRecordingWord => {
+                if let Some(ref mut recording) = self.voice_recording {
+                    recording.previous_word();
+                }
+                Task::none()
+            }
+            Message::ExitRecording => {
+                if let Some(mut recording) = self.voice_recording.take() {
+                    recording.cancel_recording();
+                }
+                self.show_screen(Screen::Settings);
+                Task::none()
+            }
         }
     }
 
-    /// Saves kiosk mode setting to config
-    fn save_kiosk_config(&self, enabled: bool) {
-        let config = crate::config::AppConfig {
-            selected_sound: self.selected_sound.clone(),
-            last_selected_grade: GradeLevel::default(),
-            use_uppercase: self.use_uppercase,
-            kiosk_mode_enabled: enabled,
-            color_palette: self.color_palette.clone(),
+    /// Starts a word challenge, resuming the active profile's adaptive state
+    fn start_challenge(&mut self, mode: ChallengeMode) {
+        let profile = self.active_profile().clone();
+        let state = profile
+            .load_adaptive_state()
+            .unwrap_or_else(|| AdaptiveState::starting_at(profile.starting_grade));
+
+        if let Some(words) = self
+            .word_list_loader
+            .get_words_for_grade(state.grade_level)
+            .cloned()
+        {
+            self.word_challenge = Some(WordChallenge::new(
+                mode,
+                state,
+                profile.load_review_schedule(),
+                words.clone(),
+            ));
+            self.show_screen(Screen::WordChallenge);
+
+            // Speak the first word, then render the rest of the list ahead of time
+            if let Some(ref challenge) = self.word_challenge {
+                self.speak(challenge.current_word.clone());
+            }
+            self.speaker
+                .prerender(words, self.config.get().speech.clone());
+        }
+    }
+
+    /// Persists the running challenge's adaptive state and review schedule
+    /// for the active profile
+    fn save_challenge_state(&mut self) {
+        let Some(ref challenge) = self.word_challenge else {
+            return;
         };
-        if let Err(e) = crate::config::save_config(&config) {
-            eprintln!("Failed to save kiosk config: {}", e);
+
+        let state = challenge.adaptive_state();
+        let profile = self.active_profile();
+        if let Err(e) = profile.save_adaptive_state(&state) {
+            eprintln!("Failed to save challenge state: {}", e);
+        }
+        if let Err(e) = profile.save_review_schedule(challenge.review_schedule()) {
+            eprintln!("Failed to save review schedule: {}", e);
+        }
+    }
+
+    /// Saves kiosk mode setting to config
+    fn save_kiosk_config(&mut self, enabled: bool) {
+        self.config
+            .update(|config| config.kiosk_mode_enabled = enabled);
+    }
+
+    /// Speaks a word with the configured voice
+    fn speak(&self, word: String) {
+        self.speaker
+            .speak_async(word, self.config.get().speech.clone());
+    }
+
+    /// Plays the sound the sound scheme maps to an event, if any
+    fn play_event(&self, event: SoundEvent) {
+        if let Some(id) = self.active_profile().sound_scheme.sound_for(event) {
+            let sound = self.sounds.get(id);
+            if event.is_celebration() {
+                self.audio.play_celebration(sound);
+            } else {
+                self.audio.play_effect(sound);
+            }
+        }
+    }
+
+    /// Plays the win or draw sound if the last tic-tac-toe move ended the game
+    fn play_game_result(&self) {
+        match self.tic_tac_toe.as_ref().map(|game| game.game_state) {
+            Some(crate::tic_tac_toe::GameState::Won(_)) => {
+                self.play_event(SoundEvent::TicTacToeWin)
+            }
+            Some(crate::tic_tac_toe::GameState::Draw) => self.play_event(SoundEvent::TicTacToeDraw),
+            _ => {}
         }
     }
 
+    /// Switches screens, playing the screen change sound
+    fn show_screen(&mut self, screen: Screen) {
+        if self.current_screen != screen {
+            self.current_screen = screen;
+            self.play_event(SoundEvent::ScreenChange);
+        }
+    }
+
+    /// Puts the word in the custom word field on the block or allow list,
+    /// taking it off the other
+    fn add_custom_word(&mut self, block: bool) -> Task<Message> {
+        let word = self.custom_word.trim().to_lowercase();
+        if word.is_empty() || !word.chars().all(|c| c.is_alphabetic()) {
+            return Task::none();
+        }
+
+        self.custom_word.clear();
+        self.config.update(|config| {
+            let settings = &mut config.dictionary;
+            if block {
+                settings.allowed_words.remove(&word);
+                settings.blocked_words.insert(word);
+            } else {
+                settings.blocked_words.remove(&word);
+                settings.allowed_words.insert(word);
+            }
+        });
+        self.reload_dictionary()
+    }
+
+    /// Rebuilds the dictionary at startup or after its settings change
+    ///
+    /// Discovery switches to the toddler words straight away, so blocked words
+    /// stop being found at once, and moves to the full dictionary once it has
+    /// loaded in the background.
+    fn reload_dictionary(&mut self) -> Task<Message> {
+        let settings = self.config.get().dictionary.clone();
+        self.dictionary = Arc::new(Dictionary::toddler_only(&settings));
+        self.dictionary_generation += 1;
+
+        let generation = self.dictionary_generation;
+        Task::perform(
+            Dictionary::load_in_background(settings),
+            move |dictionary| Message::DictionaryLoaded(generation, dictionary),
+        )
+    }
+
+    /// Returns the active profile
+    fn active_profile(&self) -> &Profile {
+        self.config.get().active_profile()
+    }
+
     /// Builds the user interface
     pub fn view(&self) -> Element<'_, Message> {
         // Route to different screens based on current_screen
@@ -412,6 +808,8 @@ impl WordsWithToddlers {
             Screen::Settings => self.build_settings_screen(),
             Screen::WordChallenge => self.build_word_challenge_screen(),
             Screen::TicTacToe => self.build_tic_tac_toe_screen(),
+            Screen::Progress => self.build_progress_screen(),
+            Screen::Recording => self.build_recording_screen(),
             Screen::Main => {
                 let mut main_column = column![].spacing(20).align_x(alignment::Horizontal::Center);
 
@@ -433,32 +831,82 @@ impl WordsWithToddlers {
 
                 main_column = main_column.push(content);
 
-                container(main_column)
+                let screen = container(main_column)
                     .width(Length::Fill)
                     .height(Length::Fill)
                     .padding(20)
                     .style(|_theme: &Theme| container::Style {
                         background: Some(iced::Background::Color(Color::from_rgb(0.05, 0.05, 0.1))),
                         ..Default::default()
+                    });
+
+                // Newly discovered words fly up from the letters into the strip
+                let letter_case = self.active_profile().letter_case;
+                let flying_words: Vec<(String, Color, f32)> = self
+                    .discovered_words
+                    .iter()
+                    .filter(|word| word.is_flying())
+                    .map(|word| {
+                        (
+                            letter_case.format_word(&word.text),
+                            word.color,
+                            word.flight_progress(),
+                        )
                     })
-                    .into()
+                    .collect();
+
+                if flying_words.is_empty() {
+                    screen.into()
+                } else {
+                    let overlay = canvas(FlyingWordsOverlay {
+                        words: flying_words,
+                    })
+                    .width(Length::Fill)
+                    .height(Length::Fill);
+                    stack![screen, overlay].into()
+                }
             }
         }
     }
 
     /// Sets up event subscriptions for keyboard input and cursor blinking
     pub fn subscription(&self) -> Subscription<Message> {
+        // Redraw every frame only while something is moving
+        let animation_frames = if self.is_animating() {
+            window::frames().map(|_| Message::AnimationFrame)
+        } else {
+            Subscription::none()
+        };
+
         Subscription::batch([
             event::listen_with(|event, _status, _id| match event {
                 Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
                     Some(Message::KeyPressed(key))
                 }
+                Event::Window(window::Event::Focused) => Some(Message::WindowFocusChanged(true)),
+                Event::Window(window::Event::Unfocused) => Some(Message::WindowFocusChanged(false)),
                 _ => None,
             }),
             iced::time::every(std::time::Duration::from_millis(530)).map(|_| Message::ToggleCursor),
+            animation_frames,
         ])
     }
 
+    /// Checks whether any animation is running and needs frames
+    fn is_animating(&self) -> bool {
+        self.celebration.is_some()
+            || self.letters.iter().any(Letter::is_animating)
+            || self.discovered_words.iter().any(DiscoveredWord::is_flying)
+    }
+
+    /// Prepares frame timing for an animation that is about to start, so it
+    /// doesn't pick up the time spent idle since the last one
+    fn start_animation(&mut self) {
+        if !self.is_animating() {
+            self.animator.restart();
+        }
+    }
+
     /// Returns the application theme
     pub fn theme(&self) -> Theme {
         Theme::Dark
@@ -479,12 +927,28 @@ impl WordsWithToddlers {
         // Handle Settings screen - Escape goes back to Welcome
         if self.current_screen == Screen::Settings {
             if let keyboard::Key::Named(keyboard::key::Named::Escape) = key {
-                self.current_screen = Screen::Welcome;
+                self.show_screen(Screen::Welcome);
                 return Task::none();
             }
             return Task::none();
         }
 
+        // Handle Recording screen - Escape goes back to Settings
+        if self.current_screen == Screen::Recording {
+            if let keyboard::Key::Named(keyboard::key::Named::Escape) = key {
+                return Task::done(Message::ExitRecording);
+            }
+            return Task::none();
+        }
+
+        // Handle Progress screen - Escape goes back to Settings
+        if self.current_screen == Screen::Progress {
+            if let keyboard::Key::Named(keyboard::key::Named::Escape) = key {
+                self.show_screen(Screen::Settings);
+            }
+            return Task::none();
+        }
+
         match key {
             keyboard::Key::Named(keyboard::key::Named::Escape) => {
                 return exit();
@@ -494,16 +958,24 @@ impl WordsWithToddlers {
                 if !self.letters.is_empty() {
                     let typed_text: String = self.letters.iter().map(|l| l.character).collect();
 
-                    let session = Session::new(
+                    let mut session = Session::new(
                         typed_text,
                         self.discovered_words
                             .iter()
                             .map(|w| w.text.clone())
                             .collect(),
                     );
+                    session.duration_seconds = self
+                        .discovery_started_at
+                        .take()
+                        .map(|started| started.elapsed().as_secs());
 
                     // Try to save the session
-                    match session.save() {
+                    match self
+                        .active_profile()
+                        .sessions_dir()
+                        .and_then(|dir| session.save(&dir))
+                    {
                         Ok(path) => eprintln!("Session saved to: {:?}", path),
                         Err(e) => eprintln!("Failed to save session: {}", e),
                     }
@@ -512,22 +984,22 @@ impl WordsWithToddlers {
                 // Check for any last word before clearing
                 self.check_and_save_word();
 
-                // Play selected sound effect
-                let sound_path = crate::system_sound::get_sound_path(&self.selected_sound);
-                crate::audio::play_sound(self.sound_playing.clone(), sound_path.to_string());
-
                 // Clear letters and discovered words, mark that we've started typing
                 self.letters.clear();
                 self.discovered_words.clear();
+                self.suggestions.clear();
                 self.has_started_typing = true;
                 // Return to welcome screen
-                self.current_screen = Screen::Welcome;
+                self.show_screen(Screen::Welcome);
             }
             keyboard::Key::Named(keyboard::key::Named::F11) => {
                 return Task::done(Message::ToggleFullscreen);
             }
             keyboard::Key::Named(keyboard::key::Named::Backspace) => {
                 self.letters.pop();
+                let letter_count = self.letters.len();
+                self.suggestions
+                    .retain(|suggestion| suggestion.after_letter < letter_count);
             }
             keyboard::Key::Character(s) => {
                 self.add_character_from_string(s.to_string());
@@ -556,16 +1028,20 @@ impl WordsWithToddlers {
             if c.is_alphabetic() || c.is_numeric() {
                 // Switch to Main screen on first character
                 if self.current_screen == Screen::Welcome {
-                    self.current_screen = Screen::Main;
+                    self.show_screen(Screen::Main);
                 }
+                self.discovery_started_at.get_or_insert_with(Instant::now);
 
-                let character = if c.is_alphabetic() {
-                    c.to_uppercase().next().unwrap()
-                } else {
-                    c
-                };
-                self.letters
-                    .push(Letter::new(character, self.random_color()));
+                // Letters keep the case they were typed in; the display applies the setting
+                self.start_animation();
+                self.letters.push(Letter::new(c, self.random_color()));
+                self.play_event(SoundEvent::Keystroke);
+
+                if let Some(utterance) = phonics::letter_utterance(c, self.active_profile().phonics)
+                {
+                    self.speaker
+                        .speak_sequence(vec![utterance], self.config.get().speech.clone());
+                }
             }
         }
     }
@@ -597,18 +1073,87 @@ impl WordsWithToddlers {
         }
 
         // Parse compound words from the segment
-        let found_words = self.dictionary.parse_compound_words(&last_segment);
+        let (found_words, suggestion) = Self::find_words(&self.dictionary, &last_segment);
+        if !found_words.is_empty() {
+            self.play_event(SoundEvent::WordDiscovered);
+            self.start_animation();
+        }
+        if let Some(word) = suggestion {
+            self.suggestions.push(WordSuggestion {
+                word,
+                after_letter: self.letters.len() - 1,
+            });
+        }
+
+        // The dictionary returns lowercase words; recover each one's spelling as
+        // typed (words come back in order) so "as typed" display can show it
+        let typed_positions: Vec<usize> = (last_space_pos..self.letters.len())
+            .filter(|&i| self.letters[i].character.is_alphabetic())
+            .collect();
+        let typed_chars: Vec<char> = typed_positions
+            .iter()
+            .map(|&i| self.letters[i].character)
+            .collect();
+        let mut search_from = 0;
+        let phonics_mode = self.active_profile().phonics;
+        let mut blended = Vec::new();
 
         // Add each found word (duplicates are OK)
         for word in found_words {
+            let len = word.chars().count();
+            let typed_word = (search_from..typed_chars.len())
+                .filter_map(|start| {
+                    let candidate: String = typed_chars.get(start..start + len)?.iter().collect();
+                    Some((start, candidate))
+                })
+                .find(|(_, candidate)| candidate.to_lowercase() == word)
+                .map(|(start, candidate)| {
+                    search_from = start + len;
+                    for &i in &typed_positions[start..start + len] {
+                        self.letters[i].highlight();
+                    }
+                    candidate
+                })
+                .unwrap_or(word);
+
+            blended.extend(phonics::blend_word(&typed_word, phonics_mode));
+
             let color = self.random_color();
-            self.discovered_words.push(DiscoveredWord::new(word, color));
+            self.discovered_words
+                .push(DiscoveredWord::new(typed_word, color));
 
             // Keep only the last 20 words
             if self.discovered_words.len() > 20 {
                 self.discovered_words.remove(0);
             }
         }
+
+        // Sound out and say each word that was found
+        if !blended.is_empty() {
+            self.speaker
+                .speak_sequence(blended, self.config.get().speech.clone());
+        }
+    }
+
+    /// Finds the words in a typed segment, along with a "did you mean" word
+    /// when some of its letters weren't part of any word
+    ///
+    /// Letters that don't fit are skipped when splitting, so a near miss can
+    /// still turn up smaller words: "dgo" finds "go" but suggests "dog". A
+    /// word that was found anyway, like "cat" in "catt", isn't suggested.
+    fn find_words(dictionary: &Dictionary, segment: &str) -> (Vec<String>, Option<String>) {
+        let found_words = dictionary.parse_compound_words(segment);
+        let letter_count = segment.chars().filter(|c| c.is_alphabetic()).count();
+        let covered: usize = found_words.iter().map(|word| word.chars().count()).sum();
+
+        let suggestion = if covered < letter_count {
+            dictionary
+                .suggest(segment)
+                .filter(|word| !found_words.contains(word))
+        } else {
+            None
+        };
+        (found_words, suggestion)
     }
 
     /// Toggles fullscreen mode
@@ -720,6 +1265,7 @@ impl WordsWithToddlers {
         container(
             column![
                 welcome_row,
+                self.build_profile_picker(),
                 instructions,
                 challenge_row,
                 tictactoe_button,
@@ -735,116 +1281,394 @@ impl WordsWithToddlers {
         .into()
     }
 
-    /// Builds the settings screen
-    fn build_settings_screen(&self) -> Element<'_, Message> {
-        let title = text("Settings")
-            .size(48)
-            .color(Color::from_rgb(0.9, 0.9, 1.0));
+    /// Builds the row of profile buttons shown on the welcome screen
+    fn build_profile_picker(&self) -> Element<'_, Message> {
+        let mut picker = row![].spacing(15).align_y(alignment::Vertical::Center);
 
-        // --- Left column: Sounds + Word Display ---
+        for profile in &self.config.get().profiles {
+            let is_active = profile.id == self.active_profile().id;
+            let (r, g, b) = profile.avatar_color();
+            let avatar_color = Color::from_rgb(r, g, b);
+
+            let label = row![
+                text("\u{25CF}").size(30).color(avatar_color),
+                text(&profile.name).size(24).color(if is_active {
+                    Color::from_rgb(1.0, 1.0, 1.0)
+                } else {
+                    Color::from_rgb(0.7, 0.7, 0.75)
+                }),
+            ]
+            .spacing(8)
+            .align_y(alignment::Vertical::Center);
+
+            let profile_button = button(label)
+                .padding(12)
+                .style(move |_theme: &Theme, _status| button::Style {
+                    background: Some(iced::Background::Color(if is_active {
+                        Color::from_rgb(0.2, 0.25, 0.35)
+                    } else {
+                        Color::from_rgb(0.15, 0.15, 0.2)
+                    })),
+                    border: iced::Border {
+                        color: if is_active {
+                            avatar_color
+                        } else {
+                            Color::from_rgb(0.3, 0.3, 0.35)
+                        },
+                        width: if is_active { 3.0 } else { 1.0 },
+                        radius: 20.0.into(),
+                    },
+                    ..Default::default()
+                })
+                .on_press(Message::SelectProfile(profile.id.clone()));
+
+            picker = picker.push(profile_button);
+        }
+
+        picker.into()
+    }
 
-        let sound_label = text("Sound Effect")
+    /// Builds the profile management section of the settings screen
+    fn build_profiles_section(&self) -> Element<'_, Message> {
+        let profiles_label = text("Profiles")
             .size(28)
             .color(Color::from_rgb(0.9, 0.9, 1.0));
 
-        let mut sounds_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
-        let sounds = crate::system_sound::SOUNDS;
-        for row_sounds in sounds.chunks(3) {
-            let mut sound_row = row![].spacing(10).align_y(alignment::Vertical::Center);
-            for sound in row_sounds {
-                let is_selected = sound.name == self.selected_sound;
-                let button_color = if is_selected {
-                    Color::from_rgb(0.2, 0.6, 0.9)
+        let mut profiles_list = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        for profile in &self.config.get().profiles {
+            let is_active = profile.id == self.active_profile().id;
+            let (r, g, b) = profile.avatar_color();
+
+            let mut profile_row = row![
+                text("\u{25CF} ").size(20).color(Color::from_rgb(r, g, b)),
+                text(&profile.name).size(20).color(if is_active {
+                    Color::from_rgb(1.0, 1.0, 1.0)
                 } else {
-                    Color::from_rgb(0.3, 0.3, 0.35)
-                };
-                let sound_button =
-                    button(text(sound.display_name).size(22).color(if is_selected {
-                        Color::from_rgb(1.0, 1.0, 1.0)
-                    } else {
-                        Color::from_rgb(0.8, 0.8, 0.8)
-                    }))
-                    .padding(12)
+                    Color::from_rgb(0.7, 0.7, 0.75)
+                }),
+            ]
+            .spacing(10)
+            .align_y(alignment::Vertical::Center);
+
+            if self.config.get().profiles.len() > 1 {
+                profile_row = profile_row.push(
+                    button(text("\u{2715}").size(16))
+                        .padding(6)
+                        .style(|_theme: &Theme, _status| button::Style {
+                            background: Some(iced::Background::Color(Color::from_rgb(
+                                0.4, 0.2, 0.2,
+                            ))),
+                            border: iced::Border {
+                                radius: 6.0.into(),
+                                ..Default::default()
+                            },
+                            text_color: Color::from_rgb(1.0, 0.8, 0.8),
+                            ..Default::default()
+                        })
+                        .on_press(Message::RemoveProfile(profile.id.clone())),
+                );
+            }
+            profiles_list = profiles_list.push(profile_row);
+        }
+
+        let name_input = text_input("New profile name", &self.new_profile_name)
+            .on_input(Message::NewProfileNameChanged)
+            .on_submit(Message::AddProfile)
+            .padding(10)
+            .size(20)
+            .width(Length::Fixed(220.0));
+
+        let add_button = button(text("Add").size(20))
+            .padding(10)
+            .on_press(Message::AddProfile);
+
+        let add_row = row![name_input, add_button]
+            .spacing(10)
+            .align_y(alignment::Vertical::Center);
+
+        // Starting grade for the active profile's challenges
+        let grade_label = text("Starting Grade")
+            .size(22)
+            .color(Color::from_rgb(0.8, 0.8, 0.9));
+
+        let starting_grade = self.active_profile().starting_grade;
+        let mut grade_row = row![].spacing(6).align_y(alignment::Vertical::Center);
+        for grade in GradeLevel::all() {
+            let is_selected = grade == starting_grade;
+            grade_row = grade_row.push(
+                button(text(grade.short_name().to_string()).size(18))
+                    .padding(8)
                     .style(move |_theme: &Theme, _status| button::Style {
-                        background: Some(iced::Background::Color(button_color)),
+                        background: Some(iced::Background::Color(if is_selected {
+                            Color::from_rgb(0.2, 0.6, 0.9)
+                        } else {
+                            Color::from_rgb(0.3, 0.3, 0.35)
+                        })),
                         border: iced::Border {
                             color: if is_selected {
                                 Color::from_rgb(0.4, 0.8, 1.0)
                             } else {
                                 Color::from_rgb(0.4, 0.4, 0.45)
                             },
-                            width: if is_selected { 3.0 } else { 1.0 },
-                            radius: 8.0.into(),
+                            width: if is_selected { 2.0 } else { 1.0 },
+                            radius: 6.0.into(),
                         },
+                        text_color: Color::from_rgb(1.0, 1.0, 1.0),
                         ..Default::default()
                     })
-                    .on_press(Message::SelectSound(sound.name.to_string()));
-                sound_row = sound_row.push(sound_button);
-            }
-            sounds_grid = sounds_grid.push(sound_row);
+                    .on_press(Message::SelectStartingGrade(grade)),
+            );
         }
 
-        let case_label = text("Word Display")
-            .size(28)
-            .color(Color::from_rgb(0.9, 0.9, 1.0));
-
-        let uppercase_button = button(text("ABC").size(22).color(
-            if self.use_uppercase {
-                Color::from_rgb(1.0, 1.0, 1.0)
-            } else {
-                Color::from_rgb(0.8, 0.8, 0.8)
-            },
-        ))
-        .padding(12)
-        .style(move |_theme: &Theme, _status| button::Style {
-            background: Some(iced::Background::Color(if self.use_uppercase {
-                Color::from_rgb(0.2, 0.6, 0.9)
-            } else {
-                Color::from_rgb(0.3, 0.3, 0.35)
-            })),
-            border: iced::Border {
-                color: if self.use_uppercase {
-                    Color::from_rgb(0.4, 0.8, 1.0)
-                } else {
-                    Color::from_rgb(0.4, 0.4, 0.45)
-                },
-                width: if self.use_uppercase { 3.0 } else { 1.0 },
-                radius: 8.0.into(),
-            },
-            ..Default::default()
+        column![
+            profiles_label,
+            profiles_list,
+            add_row,
+            grade_label,
+            grade_row
+        ]
+        .spacing(12)
+        .align_x(alignment::Horizontal::Center)
+        .into()
+    }
+
+    /// Builds the section of the settings screen that controls which words
+    /// can be discovered
+    fn build_dictionary_section(&self) -> Element<'_, Message> {
+        let settings = &self.config.get().dictionary;
+
+        let dictionary_label = text("Words").size(28).color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let source_row = row![
+            Self::option_button(
+                "Built-in Words",
+                !settings.use_system_dictionary,
+                Message::SelectSystemDictionary(false),
+            ),
+            Self::option_button(
+                "+ System Words",
+                settings.use_system_dictionary,
+                Message::SelectSystemDictionary(true),
+            ),
+        ]
+        .spacing(10)
+        .align_y(alignment::Vertical::Center);
+
+        // Each rank of the bundled common words holds about a hundred words;
+        // the sight words in the last rank are only included by "All"
+        let mut rank_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+        for (label, max_rank) in [
+            ("Top 100", Some(1)),
+            ("Top 300", Some(3)),
+            ("Top 500", Some(5)),
+            ("All", None),
+        ] {
+            rank_row = rank_row.push(Self::option_button(
+                label,
+                settings.max_word_rank == max_rank,
+                Message::SelectMaxWordRank(max_rank),
+            ));
+        }
+
+        let rank_hint = text(if settings.max_word_rank.is_some() {
+            "Only the most common built-in words are found"
+        } else {
+            "Every built-in word can be found"
         })
-        .on_press(Message::ToggleUppercase(true));
+        .size(16)
+        .color(Color::from_rgb(0.7, 0.7, 0.8));
 
-        let lowercase_button = button(text("abc").size(22).color(
-            if !self.use_uppercase {
-                Color::from_rgb(1.0, 1.0, 1.0)
-            } else {
-                Color::from_rgb(0.8, 0.8, 0.8)
-            },
-        ))
+        let word_input = text_input("Word", &self.custom_word)
+            .on_input(Message::CustomWordChanged)
+            .padding(10)
+            .size(20)
+            .width(Length::Fixed(180.0));
+
+        let block_button = button(text("Block").size(20))
+            .padding(10)
+            .on_press(Message::BlockCustomWord);
+
+        let allow_button = button(text("Allow").size(20))
+            .padding(10)
+            .on_press(Message::AllowCustomWord);
+
+        let input_row = row![word_input, block_button, allow_button]
+            .spacing(10)
+            .align_y(alignment::Vertical::Center);
+
+        // Blocked words in red and allowed words in green; tapping one removes it
+        let custom_words: Vec<(&String, bool)> = settings
+            .blocked_words
+            .iter()
+            .map(|word| (word, true))
+            .chain(settings.allowed_words.iter().map(|word| (word, false)))
+            .collect();
+
+        let mut words_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        for row_words in custom_words.chunks(4) {
+            let mut words_row = row![].spacing(8).align_y(alignment::Vertical::Center);
+            for &(word, blocked) in row_words {
+                let background = if blocked {
+                    Color::from_rgb(0.45, 0.2, 0.2)
+                } else {
+                    Color::from_rgb(0.2, 0.4, 0.25)
+                };
+                words_row = words_row.push(
+                    button(text(format!("{} \u{2715}", word)).size(16))
+                        .padding(6)
+                        .style(move |_theme: &Theme, _status| button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                radius: 6.0.into(),
+                                ..Default::default()
+                            },
+                            text_color: Color::from_rgb(1.0, 0.95, 0.95),
+                            ..Default::default()
+                        })
+                        .on_press(Message::RemoveCustomWord(word.clone())),
+                );
+            }
+            words_grid = words_grid.push(words_row);
+        }
+
+        column![
+            dictionary_label,
+            source_row,
+            rank_row,
+            rank_hint,
+            input_row,
+            words_grid
+        ]
+        .spacing(12)
+        .align_x(alignment::Horizontal::Center)
+        .into()
+    }
+
+    /// Builds a settings button that is highlighted when selected
+    fn option_button(
+        label: impl Into<String>,
+        is_selected: bool,
+        message: Message,
+    ) -> Element<'static, Message> {
+        let button_color = if is_selected {
+            Color::from_rgb(0.2, 0.6, 0.9)
+        } else {
+            Color::from_rgb(0.3, 0.3, 0.35)
+        };
+
+        button(text(label.into()).size(22).color(if is_selected {
+            Color::from_rgb(1.0, 1.0, 1.0)
+        } else {
+            Color::from_rgb(0.8, 0.8, 0.8)
+        }))
         .padding(12)
         .style(move |_theme: &Theme, _status| button::Style {
-            background: Some(iced::Background::Color(if !self.use_uppercase {
-                Color::from_rgb(0.2, 0.6, 0.9)
-            } else {
-                Color::from_rgb(0.3, 0.3, 0.35)
-            })),
+            background: Some(iced::Background::Color(button_color)),
             border: iced::Border {
-                color: if !self.use_uppercase {
+                color: if is_selected {
                     Color::from_rgb(0.4, 0.8, 1.0)
                 } else {
                     Color::from_rgb(0.4, 0.4, 0.45)
                 },
-                width: if !self.use_uppercase { 3.0 } else { 1.0 },
+                width: if is_selected { 3.0 } else { 1.0 },
                 radius: 8.0.into(),
             },
             ..Default::default()
         })
-        .on_press(Message::ToggleUppercase(false));
+        .on_press(message)
+        .into()
+    }
 
-        let case_toggle_row = row![uppercase_button, lowercase_button]
-            .spacing(10)
-            .align_y(alignment::Vertical::Center);
+    /// Builds the settings screen
+    fn build_settings_screen(&self) -> Element<'_, Message> {
+        let title = text("Settings")
+            .size(48)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        // --- Left column: Sounds + Word Display ---
+
+        let sound_label = text("Sounds")
+            .size(28)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        // Pick an event, then the sound it plays
+        let mut events_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        for row_events in SoundEvent::all().chunks(3) {
+            let mut event_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+            for &event in row_events {
+                event_row = event_row.push(Self::option_button(
+                    event.display_name(),
+                    event == self.editing_sound_event,
+                    Message::SelectSoundEvent(event),
+                ));
+            }
+            events_grid = events_grid.push(event_row);
+        }
+
+        let current_sound = self
+            .active_profile()
+            .sound_scheme
+            .sound_for(self.editing_sound_event);
+        let choices: Vec<(String, Option<String>)> = std::iter::once(("Off".to_string(), None))
+            .chain(
+                self.sounds
+                    .all()
+                    .iter()
+                    .map(|sound| (sound.display_name.clone(), Some(sound.id.clone()))),
+            )
+            .collect();
+
+        let mut sounds_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        for row_sounds in choices.chunks(3) {
+            let mut sound_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+            for (label, id) in row_sounds {
+                sound_row = sound_row.push(Self::option_button(
+                    label.clone(),
+                    id.as_deref() == current_sound,
+                    Message::SelectSound(id.clone()),
+                ));
+            }
+            sounds_grid = sounds_grid.push(sound_row);
+        }
+
+        let case_label = text("Word Display")
+            .size(28)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let mut case_toggle_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+        for &letter_case in LetterCase::all() {
+            case_toggle_row = case_toggle_row.push(Self::option_button(
+                letter_case.display_name(),
+                letter_case == self.active_profile().letter_case,
+                Message::SelectLetterCase(letter_case),
+            ));
+        }
+
+        let phonics_label = text("Phonics")
+            .size(28)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let mut phonics_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+        for &mode in PhonicsMode::all() {
+            phonics_row = phonics_row.push(Self::option_button(
+                mode.display_name(),
+                mode == self.active_profile().phonics,
+                Message::SelectPhonicsMode(mode),
+            ));
+        }
+
+        let celebration_label = text("Celebration")
+            .size(28)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let mut celebration_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+        for &style in CelebrationStyle::all() {
+            celebration_row = celebration_row.push(Self::option_button(
+                style.display_name(),
+                style == self.active_profile().celebration_style,
+                Message::SelectCelebrationStyle(style),
+            ));
+        }
 
         // Kiosk mode section (macOS only)
         #[cfg(target_os = "macos")]
@@ -857,7 +1681,7 @@ impl WordsWithToddlers {
                 .size(16)
                 .color(Color::from_rgb(0.6, 0.6, 0.6));
 
-            let kiosk_enabled = self.kiosk_mode_enabled;
+            let kiosk_enabled = self.config.get().kiosk_mode_enabled;
 
             let enable_button = button(text("On").size(22).color(
                 if kiosk_enabled {
@@ -886,121 +1710,555 @@ impl WordsWithToddlers {
             })
             .on_press(Message::ToggleKioskMode(true));
 
-            let disable_button = button(text("Off").size(22).color(
-                if !kiosk_enabled {
-                    Color::from_rgb(1.0, 1.0, 1.0)
-                } else {
-                    Color::from_rgb(0.8, 0.8, 0.8)
-                },
-            ))
-            .padding(12)
+            let disable_button = button(text("Off").size(22).color(
+                if !kiosk_enabled {
+                    Color::from_rgb(1.0, 1.0, 1.0)
+                } else {
+                    Color::from_rgb(0.8, 0.8, 0.8)
+                },
+            ))
+            .padding(12)
+            .style(move |_theme: &Theme, _status| button::Style {
+                background: Some(iced::Background::Color(if !kiosk_enabled {
+                    Color::from_rgb(0.6, 0.2, 0.2)
+                } else {
+                    Color::from_rgb(0.3, 0.3, 0.35)
+                })),
+                border: iced::Border {
+                    color: if !kiosk_enabled {
+                        Color::from_rgb(0.8, 0.4, 0.4)
+                    } else {
+                        Color::from_rgb(0.4, 0.4, 0.45)
+                    },
+                    width: if !kiosk_enabled { 3.0 } else { 1.0 },
+                    radius: 8.0.into(),
+                },
+                ..Default::default()
+            })
+            .on_press(Message::ToggleKioskMode(false));
+
+            let kiosk_toggle_row = row![enable_button, disable_button]
+                .spacing(10)
+                .align_y(alignment::Vertical::Center);
+
+            column![kiosk_label, kiosk_desc, kiosk_toggle_row]
+                .spacing(10)
+                .align_x(alignment::Horizontal::Center)
+        };
+
+        #[cfg(not(target_os = "macos"))]
+        let kiosk_section = column![];
+
+        let left_column = column![
+            sound_label,
+            events_grid,
+            sounds_grid,
+            case_label,
+            case_toggle_row,
+            phonics_label,
+            phonics_row,
+            celebration_label,
+            celebration_row,
+            kiosk_section
+        ]
+            .spacing(20)
+            .align_x(alignment::Horizontal::Center)
+            .width(Length::FillPortion(1));
+
+        // --- Right column: Color Palette ---
+
+        let palette_label = text("Letter Colors")
+            .size(28)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let mut palette_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        let palettes = ColorPalette::all();
+        for row_palettes in palettes.chunks(2) {
+            let mut palette_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+            for palette in row_palettes {
+                let is_selected = *palette == self.active_profile().color_palette;
+                let (pr, pg, pb) = palette.preview_color();
+                let preview_color = Color::from_rgb(pr, pg, pb);
+                let palette_clone = palette.clone();
+
+                let label_row = row![
+                    text("\u{25CF} ").size(20).color(preview_color),
+                    text(palette.display_name()).size(20).color(if is_selected {
+                        Color::from_rgb(1.0, 1.0, 1.0)
+                    } else {
+                        Color::from_rgb(0.8, 0.8, 0.8)
+                    }),
+                ]
+                .align_y(alignment::Vertical::Center);
+
+                let button_color = if is_selected {
+                    Color::from_rgb(0.2, 0.6, 0.9)
+                } else {
+                    Color::from_rgb(0.3, 0.3, 0.35)
+                };
+
+                let palette_button = button(label_row)
+                    .padding(12)
+                    .width(Length::Fill)
+                    .style(move |_theme: &Theme, _status| button::Style {
+                        background: Some(iced::Background::Color(button_color)),
+                        border: iced::Border {
+                            color: if is_selected {
+                                Color::from_rgb(0.4, 0.8, 1.0)
+                            } else {
+                                Color::from_rgb(0.4, 0.4, 0.45)
+                            },
+                            width: if is_selected { 3.0 } else { 1.0 },
+                            radius: 8.0.into(),
+                        },
+                        ..Default::default()
+                    })
+                    .on_press(Message::SelectColorPalette(palette_clone));
+
+                palette_row = palette_row.push(palette_button);
+            }
+            palette_grid = palette_grid.push(palette_row);
+        }
+
+        let music_label = text("Music").size(28).color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let audio_settings = &self.config.get().audio;
+        let track_choices: Vec<(String, Option<String>)> =
+            std::iter::once(("Off".to_string(), None))
+                .chain(
+                    self.music
+                        .all()
+                        .iter()
+                        .map(|track| (track.display_name.clone(), Some(track.id.clone()))),
+                )
+                .collect();
+
+        let mut music_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
+        for row_tracks in track_choices.chunks(3) {
+            let mut track_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+            for (label, id) in row_tracks {
+                track_row = track_row.push(Self::option_button(
+                    label.clone(),
+                    *id == audio_settings.music_track,
+                    Message::SelectMusicTrack(id.clone()),
+                ));
+            }
+            music_grid = music_grid.push(track_row);
+        }
+
+        let mut music_volume_row = row![].spacing(10).align_y(alignment::Vertical::Center);
+        for (label, volume) in music::VOLUME_PRESETS {
+            music_volume_row = music_volume_row.push(Self::option_button(
+                label,
+                (audio_settings.music_volume - volume).abs() < 0.01,
+                Message::SelectMusicVolume(volume),
+            ));
+        }
+
+        let right_column = column![
+            palette_label,
+            palette_grid,
+            music_label,
+            music_grid,
+            music_volume_row,
+            self.build_profiles_section(),
+            self.build_dictionary_section()
+        ]
+        .spacing(20)
+        .align_x(alignment::Horizontal::Center)
+        .width(Length::FillPortion(1));
+
+        // --- Assemble layout ---
+
+        let columns = row![left_column, right_column]
+            .spacing(40)
+            .align_y(alignment::Vertical::Top);
+
+        let progress_button = button(text("\u{1F4C8} Progress").size(22))
+            .padding(12)
+            .style(|_theme: &Theme, _status| button::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.2, 0.5, 0.4))),
+                border: iced::Border {
+                    color: Color::from_rgb(0.4, 0.8, 0.6),
+                    width: 2.0,
+                    radius: 8.0.into(),
+                },
+                ..Default::default()
+            })
+            .on_press(Message::NavigateToProgress);
+
+        let record_button = button(text("\u{1F399} Record Voice").size(22))
+            .padding(12)
+            .style(|_theme: &Theme, _status| button::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.5, 0.25, 0.45))),
+                border: iced::Border {
+                    color: Color::from_rgb(0.8, 0.5, 0.75),
+                    width: 2.0,
+                    radius: 8.0.into(),
+                },
+                ..Default::default()
+            })
+            .on_press(Message::NavigateToRecording);
+
+        let back_button = button(text("\u{2190} Back to Welcome").size(22))
+            .padding(12)
+            .on_press(Message::NavigateToWelcome);
+
+        let bottom_row = row![progress_button, record_button, back_button]
+            .spacing(20)
+            .align_y(alignment::Vertical::Center);
+
+        let content = column![title]
+            .push_maybe(self.build_config_error_banner())
+            .push(scrollable(columns).height(Length::Fill))
+            .push(bottom_row)
+            .spacing(25)
+            .padding(30)
+            .align_x(alignment::Horizontal::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+            .into()
+    }
+
+    /// Builds a banner describing the last settings load/save failure, if any
+    fn build_config_error_banner(&self) -> Option<Element<'_, Message>> {
+        let error = self.config.last_error()?;
+
+        let dismiss_button = button(text("\u{2715}").size(18))
+            .padding([4, 10])
+            .style(|_theme: &Theme, _status| button::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.5, 0.2, 0.2))),
+                text_color: Color::WHITE,
+                border: iced::Border {
+                    radius: 6.0.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .on_press(Message::DismissConfigError);
+
+        let banner = row![
+            text(error).size(20).color(Color::from_rgb(1.0, 0.85, 0.85)),
+            dismiss_button
+        ]
+        .spacing(15)
+        .align_y(alignment::Vertical::Center);
+
+        Some(
+            container(banner)
+                .padding([10, 20])
+                .style(|_theme: &Theme| container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgb(0.35, 0.12, 0.12))),
+                    border: iced::Border {
+                        color: Color::from_rgb(0.8, 0.3, 0.3),
+                        width: 2.0,
+                        radius: 8.0.into(),
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    /// Builds the parent screen for recording voice pack clips word by word
+    fn build_recording_screen(&self) -> Element<'_, Message> {
+        let title = text("Record Voice")
+            .size(48)
+            .color(Color::from_rgb(0.9, 0.9, 1.0));
+
+        let back_button = button(text("\u{2190} Back to Settings").size(22))
+            .padding(12)
+            .on_press(Message::ExitRecording);
+
+        let Some(ref recording) = self.voice_recording else {
+            return container(column![title, back_button].spacing(25))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(alignment::Horizontal::Center)
+                .align_y(alignment::Vertical::Center)
+                .into();
+        };
+
+        let muted_color = Color::from_rgb(0.5, 0.5, 0.6);
+        let is_recording = recording.is_recording();
+
+        let pack_label = text(format!("Voice pack: {}", recording.pack.name()))
+            .size(22)
+            .color(muted_color);
+
+        // Grade picker
+        let mut grade_row = row![].spacing(6).align_y(alignment::Vertical::Center);
+        for grade in GradeLevel::all() {
+            let is_selected = grade == recording.grade_level;
+            grade_row = grade_row.push(
+                button(text(grade.short_name().to_string()).size(18))
+                    .padding(8)
+                    .style(move |_theme: &Theme, _status| button::Style {
+                        background: Some(iced::Background::Color(if is_selected {
+                            Color::from_rgb(0.2, 0.6, 0.9)
+                        } else {
+                            Color::from_rgb(0.3, 0.3, 0.35)
+                        })),
+                        border: iced::Border {
+                            color: if is_selected {
+                                Color::from_rgb(0.4, 0.8, 1.0)
+                            } else {
+                                Color::from_rgb(0.4, 0.4, 0.45)
+                            },
+                            width: if is_selected { 2.0 } else { 1.0 },
+                            radius: 6.0.into(),
+                        },
+                        text_color: Color::from_rgb(1.0, 1.0, 1.0),
+                        ..Default::default()
+                    })
+                    .on_press_maybe(
+                        (!is_recording).then_some(Message::SelectRecordingGrade(grade)),
+                    ),
+            );
+        }
+
+        // Current word and how far through the list we are
+        let word = recording.current_word().unwrap_or("");
+        let has_clip = recording.pack.has_clip(word);
+        let word_color = if is_recording {
+            Color::from_rgb(1.0, 0.4, 0.4)
+        } else if has_clip {
+            Color::from_rgb(0.4, 0.9, 0.5)
+        } else {
+            Color::from_rgb(0.9, 0.9, 1.0)
+        };
+        let word_text = text(word.to_string()).size(120).color(word_color);
+
+        let position_label = text(format!(
+            "Word {} of {} \u{2022} {} recorded{}",
+            (recording.index + 1).min(recording.words.len()),
+            recording.words.len(),
+            recording.recorded_count(),
+            if has_clip {
+                " \u{2022} \u{2713} this word"
+            } else {
+                ""
+            }
+        ))
+        .size(22)
+        .color(muted_color);
+
+        // Controls
+        let record_label = if is_recording {
+            "\u{25A0} Stop"
+        } else {
+            "\u{25CF} Record"
+        };
+        let record_button = button(text(record_label).size(28))
+            .padding([14, 28])
             .style(move |_theme: &Theme, _status| button::Style {
-                background: Some(iced::Background::Color(if !kiosk_enabled {
-                    Color::from_rgb(0.6, 0.2, 0.2)
+                background: Some(iced::Background::Color(if is_recording {
+                    Color::from_rgb(0.7, 0.15, 0.15)
                 } else {
-                    Color::from_rgb(0.3, 0.3, 0.35)
+                    Color::from_rgb(0.5, 0.2, 0.2)
                 })),
+                text_color: Color::WHITE,
                 border: iced::Border {
-                    color: if !kiosk_enabled {
-                        Color::from_rgb(0.8, 0.4, 0.4)
-                    } else {
-                        Color::from_rgb(0.4, 0.4, 0.45)
-                    },
-                    width: if !kiosk_enabled { 3.0 } else { 1.0 },
-                    radius: 8.0.into(),
+                    color: Color::from_rgb(1.0, 0.4, 0.4),
+                    width: 2.0,
+                    radius: 10.0.into(),
                 },
                 ..Default::default()
             })
-            .on_press(Message::ToggleKioskMode(false));
+            .on_press_maybe((!word.is_empty()).then_some(Message::ToggleRecording));
 
-            let kiosk_toggle_row = row![enable_button, disable_button]
-                .spacing(10)
-                .align_y(alignment::Vertical::Center);
+        let play_button = button(text("\u{25B6} Play").size(28))
+            .padding([14, 28])
+            .on_press_maybe((has_clip && !is_recording).then_some(Message::PlayRecordedClip));
 
-            column![kiosk_label, kiosk_desc, kiosk_toggle_row]
-                .spacing(10)
-                .align_x(alignment::Horizontal::Center)
-        };
+        let previous_button = button(text("\u{2190} Previous").size(22))
+            .padding(12)
+            .on_press_maybe((recording.index > 0).then_some(Message::PreviousRecordingWord));
 
-        #[cfg(not(target_os = "macos"))]
-        let kiosk_section = column![];
+        let next_button = button(text("Next \u{2192}").size(22))
+            .padding(12)
+            .on_press_maybe(
+                (recording.index + 1 < recording.words.len()).then_some(Message::NextRecordingWord),
+            );
 
-        let left_column = column![sound_label, sounds_grid, case_label, case_toggle_row, kiosk_section]
+        let controls = row![previous_button, record_button, play_button, next_button]
             .spacing(20)
-            .align_x(alignment::Horizontal::Center)
-            .width(Length::FillPortion(1));
+            .align_y(alignment::Vertical::Center);
 
-        // --- Right column: Color Palette ---
+        let status = text(recording.status.clone().unwrap_or_default())
+            .size(22)
+            .color(Color::from_rgb(0.75, 0.75, 0.8));
+
+        let content = column![
+            title,
+            pack_label,
+            grade_row,
+            word_text,
+            position_label,
+            controls,
+            status,
+            back_button
+        ]
+        .spacing(25)
+        .padding(30)
+        .align_x(alignment::Horizontal::Center);
 
-        let palette_label = text("Letter Colors")
-            .size(28)
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+            .into()
+    }
+
+    /// Builds the parent progress dashboard from saved sessions
+    fn build_progress_screen(&self) -> Element<'_, Message> {
+        let title = text("Progress")
+            .size(48)
             .color(Color::from_rgb(0.9, 0.9, 1.0));
 
-        let mut palette_grid = column![].spacing(8).align_x(alignment::Horizontal::Center);
-        let palettes = ColorPalette::all();
-        for row_palettes in palettes.chunks(2) {
-            let mut palette_row = row![].spacing(10).align_y(alignment::Vertical::Center);
-            for palette in row_palettes {
-                let is_selected = *palette == self.color_palette;
-                let (pr, pg, pb) = palette.preview_color();
-                let preview_color = Color::from_rgb(pr, pg, pb);
-                let palette_clone = palette.clone();
+        let back_button = button(text("\u{2190} Back to Settings").size(22))
+            .padding(12)
+            .on_press(Message::NavigateToSettings);
 
-                let label_row = row![
-                    text("\u{25CF} ").size(20).color(preview_color),
-                    text(palette.display_name()).size(20).color(if is_selected {
-                        Color::from_rgb(1.0, 1.0, 1.0)
+        let Some(ref report) = self.progress_report else {
+            let loading = text("Loading\u{2026}")
+                .size(24)
+                .color(Color::from_rgb(0.75, 0.75, 0.8));
+            return container(column![title, loading, back_button].spacing(25))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(alignment::Horizontal::Center)
+                .align_y(alignment::Vertical::Center)
+                .into();
+        };
+
+        let section_label = |label: &str| {
+            text(label.to_string())
+                .size(28)
+                .color(Color::from_rgb(0.9, 0.9, 1.0))
+        };
+        let detail_color = Color::from_rgb(0.75, 0.75, 0.8);
+        let muted_color = Color::from_rgb(0.5, 0.5, 0.6);
+
+        // --- Headline numbers ---
+
+        let accuracy_label = report
+            .summary
+            .accuracy()
+            .map(|a| format!("{:.0}%", a * 100.0))
+            .unwrap_or_else(|| "\u{2014}".to_string());
+
+        let stat = |value: String, label: &str, color: Color| {
+            column![
+                text(value).size(44).color(color),
+                text(label.to_string()).size(18).color(muted_color),
+            ]
+            .spacing(4)
+            .align_x(alignment::Horizontal::Center)
+        };
+
+        let stats_row = row![
+            stat(
+                report.practice_time_label(),
+                "Practice Time",
+                Color::from_rgb(1.0, 0.8, 0.2)
+            ),
+            stat(
+                report.summary.session_count.to_string(),
+                "Sessions",
+                Color::from_rgb(0.5, 0.8, 1.0)
+            ),
+            stat(accuracy_label, "Accuracy", Color::from_rgb(0.5, 1.0, 0.8)),
+        ]
+        .spacing(60)
+        .align_y(alignment::Vertical::Center);
+
+        // --- Left column: mastery + most missed ---
+
+        let mut mastered_column = column![section_label("Words Mastered")].spacing(6);
+        for (grade, count) in &report.mastered_by_grade {
+            mastered_column = mastered_column.push(
+                text(format!("{}: {}", grade.display_name(), count))
+                    .size(20)
+                    .color(if *count > 0 {
+                        detail_color
                     } else {
-                        Color::from_rgb(0.8, 0.8, 0.8)
+                        muted_color
                     }),
-                ]
-                .align_y(alignment::Vertical::Center);
+            );
+        }
 
-                let button_color = if is_selected {
-                    Color::from_rgb(0.2, 0.6, 0.9)
-                } else {
-                    Color::from_rgb(0.3, 0.3, 0.35)
-                };
+        let mut missed_column = column![section_label("Most Missed")].spacing(6);
+        if report.most_missed.is_empty() {
+            missed_column =
+                missed_column.push(text("No missed words yet").size(20).color(muted_color));
+        }
+        for (word, misses) in &report.most_missed {
+            missed_column = missed_column.push(
+                text(format!("{} \u{2014} missed {}", word, misses))
+                    .size(20)
+                    .color(detail_color),
+            );
+        }
 
-                let palette_button = button(label_row)
-                    .padding(12)
-                    .width(Length::Fill)
-                    .style(move |_theme: &Theme, _status| button::Style {
-                        background: Some(iced::Background::Color(button_color)),
-                        border: iced::Border {
-                            color: if is_selected {
-                                Color::from_rgb(0.4, 0.8, 1.0)
-                            } else {
-                                Color::from_rgb(0.4, 0.4, 0.45)
-                            },
-                            width: if is_selected { 3.0 } else { 1.0 },
-                            radius: 8.0.into(),
-                        },
-                        ..Default::default()
-                    })
-                    .on_press(Message::SelectColorPalette(palette_clone));
+        let left_column = column![mastered_column, missed_column]
+            .spacing(25)
+            .width(Length::FillPortion(1));
 
-                palette_row = palette_row.push(palette_button);
-            }
-            palette_grid = palette_grid.push(palette_row);
+        // --- Right column: accuracy trend + level history ---
+
+        let chart: Element<'_, Message> = if report.daily_accuracy.is_empty() {
+            text("Play a challenge to see accuracy over time")
+                .size(20)
+                .color(muted_color)
+                .into()
+        } else {
+            canvas(AccuracyChart {
+                points: report
+                    .daily_accuracy
+                    .iter()
+                    .rev()
+                    .take(14)
+                    .rev()
+                    .cloned()
+                    .collect(),
+            })
+            .width(Length::Fill)
+            .height(Length::Fixed(200.0))
+            .into()
+        };
+
+        let mut history_column = column![section_label("Level History")].spacing(6);
+        if report.level_history.is_empty() {
+            history_column =
+                history_column.push(text("No challenges yet").size(20).color(muted_color));
+        }
+        for (at, grade) in report.level_history.iter().rev().take(8) {
+            history_column = history_column.push(
+                text(format!(
+                    "{} \u{2192} {}",
+                    at.format("%b %-d"),
+                    grade.display_name()
+                ))
+                .size(20)
+                .color(detail_color),
+            );
         }
 
-        let right_column = column![palette_label, palette_grid]
-            .spacing(20)
-            .align_x(alignment::Horizontal::Center)
+        let right_column = column![section_label("Accuracy Over Time"), chart, history_column]
+            .spacing(15)
             .width(Length::FillPortion(1));
 
-        // --- Assemble layout ---
-
         let columns = row![left_column, right_column]
             .spacing(40)
             .align_y(alignment::Vertical::Top);
 
-        let back_button = button(text("\u{2190} Back to Welcome").size(22))
-            .padding(12)
-            .on_press(Message::NavigateToWelcome);
-
-        let content = column![title, columns, back_button]
+        let content = column![title, stats_row, scrollable(columns), back_button]
             .spacing(25)
             .padding(30)
             .align_x(alignment::Horizontal::Center);
@@ -1024,8 +2282,10 @@ impl WordsWithToddlers {
         let max_width = 1100.0; // Approximate max width before wrapping
         let char_width = 25.0; // Approximate width per character at size 40
 
+        let letter_case = self.active_profile().letter_case;
         for word in &self.discovered_words {
-            let word_width = word.text.len() as f32 * char_width + 20.0; // Add spacing
+            let displayed_word = letter_case.format_word(&word.text);
+            let word_width = displayed_word.chars().count() as f32 * char_width + 20.0; // Add spacing
 
             // Check if we need to wrap to next line
             if row_width + word_width > max_width && !current_row.is_empty() {
@@ -1038,8 +2298,13 @@ impl WordsWithToddlers {
                 row_width = 0.0;
             }
 
-            // Add word to current row
-            current_row.push(text(&word.text).size(40).color(word.color).into());
+            // Add word to current row, hidden until it has flown up into place
+            let color = if word.is_flying() {
+                Color::TRANSPARENT
+            } else {
+                word.color
+            };
+            current_row.push(text(displayed_word).size(40).color(color).into());
             row_width += word_width;
         }
 
@@ -1090,13 +2355,34 @@ impl WordsWithToddlers {
         let mut current_row = row![].spacing(5).align_y(alignment::Vertical::Center);
         let mut letter_count = 0;
 
-        for letter in &self.letters {
-            // Add letter to current row
+        let letter_case = self.active_profile().letter_case;
+        for (index, letter) in self.letters.iter().enumerate() {
+            // Add letter to current row; its box keeps the full height while
+            // the letter pops in so the rows don't shift
             current_row = current_row.push(
-                text(letter.character.to_string())
-                    .size(letter_size)
-                    .color(letter.color),
+                container(
+                    text(letter_case.format_char(letter.character))
+                        .size(letter_size as f32 * letter.scale())
+                        .color(letter.display_color()),
+                )
+                .height(Length::Fixed(letter_size as f32 * 1.3))
+                .align_y(alignment::Vertical::Center),
             );
+
+            // A near miss gets a gentle hint right after it
+            if let Some(suggestion) = self.suggestions.iter().find(|s| s.after_letter == index) {
+                current_row = current_row.push(
+                    container(
+                        text(format!(
+                            "did you mean {}?",
+                            letter_case.format_word(&suggestion.word)
+                        ))
+                        .size(36)
+                        .color(Color::from_rgb(0.75, 0.75, 0.9)),
+                    )
+                    .padding([0, 15]),
+                );
+            }
             letter_count += 1;
 
             // Check if we need to wrap to next line
@@ -1139,7 +2425,8 @@ impl WordsWithToddlers {
     fn random_color(&self) -> Color {
         use rand::Rng;
         let mut rng = rand::thread_rng();
-        let (hue_min, hue_max, saturation, lightness) = self.color_palette.color_params();
+        let (hue_min, hue_max, saturation, lightness) =
+            self.active_profile().color_palette.color_params();
         let hue = rng.gen_range(hue_min..hue_max);
         let (r, g, b) = hsl_to_rgb(hue, saturation, lightness);
         Color::from_rgb(r, g, b)
@@ -1148,6 +2435,7 @@ impl WordsWithToddlers {
     /// Builds the word challenge screen
     fn build_word_challenge_screen(&self) -> Element<'_, Message> {
         if let Some(ref challenge) = self.word_challenge {
+            let letter_case = self.active_profile().letter_case;
             let mut content_column = column![].spacing(40).align_x(alignment::Horizontal::Center);
 
             // Score and difficulty display
@@ -1181,11 +2469,7 @@ impl WordsWithToddlers {
                 150
             };
 
-            let displayed_word = if self.use_uppercase {
-                challenge.current_word.to_uppercase()
-            } else {
-                challenge.current_word.clone()
-            };
+            let displayed_word = letter_case.format_word(&challenge.current_word);
             let target_word = text(displayed_word)
                 .size(target_size)
                 .color(target_word_color);
@@ -1224,7 +2508,7 @@ impl WordsWithToddlers {
 
                 for letter in &challenge.typed_letters {
                     typed_row = typed_row.push(
-                        text(letter.character.to_string())
+                        text(letter_case.format_char(letter.character))
                             .size(100)
                             .color(letter.color),
                     );
@@ -1263,7 +2547,7 @@ impl WordsWithToddlers {
 
             content_column = content_column.push(instructions);
 
-            container(content_column)
+            let content = container(content_column)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .align_x(alignment::Horizontal::Center)
@@ -1271,8 +2555,16 @@ impl WordsWithToddlers {
                 .style(|_theme: &Theme| container::Style {
                     background: Some(iced::Background::Color(Color::from_rgb(0.05, 0.05, 0.1))),
                     ..Default::default()
-                })
-                .into()
+                });
+
+            if let Some(ref celebration) = self.celebration {
+                let overlay = canvas(CelebrationOverlay { celebration })
+                    .width(Length::Fill)
+                    .height(Length::Fill);
+                stack![content, overlay].into()
+            } else {
+                content.into()
+            }
         } else {
             // Fallback if no challenge exists
             self.build_welcome_screen()
@@ -1518,11 +2810,11 @@ impl WordsWithToddlers {
             keyboard::Key::Character(s) => {
                 if let Some(c) = s.chars().next() {
                     if c.is_alphabetic() {
-                        let character = c.to_uppercase().next().unwrap();
                         let color = self.random_color();
+                        self.play_event(SoundEvent::Keystroke);
 
                         if let Some(ref mut challenge) = self.word_challenge {
-                            challenge.add_letter(Letter::new(character, color));
+                            challenge.add_letter(Letter::new(c, color));
 
                             // Auto-check if word length matches
                             if challenge.typed_text().len() == challenge.current_word.len() {
@@ -1604,3 +2896,219 @@ impl<Message> canvas::Program<Message> for WinningLineOverlay {
         vec![frame.into_geometry()]
     }
 }
+
+/// Canvas program that draws celebration particles bursting from the middle
+/// of the screen, where the challenge word is shown
+struct CelebrationOverlay<'a> {
+    celebration: &'a Celebration,
+}
+
+impl CelebrationOverlay<'_> {
+    /// Builds a five-pointed star around the origin
+    fn star_path(radius: f32) -> canvas::Path {
+        canvas::Path::new(|builder| {
+            for i in 0..10 {
+                let r = if i % 2 == 0 { radius } else { radius * 0.45 };
+                let angle = i as f32 * std::f32::consts::PI / 5.0 - std::f32::consts::FRAC_PI_2;
+                let point = Point::new(angle.cos() * r, angle.sin() * r);
+                if i == 0 {
+                    builder.move_to(point);
+                } else {
+                    builder.line_to(point);
+                }
+            }
+            builder.close();
+        })
+    }
+}
+
+impl<Message> canvas::Program<Message> for CelebrationOverlay<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let center = frame.center();
+
+        for particle in self.celebration.visible_particles() {
+            let color = Color {
+                a: particle.color.a * particle.opacity(),
+                ..particle.color
+            };
+            let size = particle.size;
+
+            frame.with_save(|frame| {
+                frame.translate(Vector::new(center.x + particle.x, center.y + particle.y));
+                frame.rotate(particle.rotation);
+                match particle.shape {
+                    ParticleShape::Confetti => {
+                        frame.fill_rectangle(
+                            Point::new(-size / 2.0, -size / 4.0),
+                            Size::new(size, size / 2.0),
+                            color,
+                        );
+                    }
+                    ParticleShape::Spark => {
+                        frame.fill(&canvas::Path::circle(Point::ORIGIN, size), color);
+                    }
+                    ParticleShape::Balloon => {
+                        let string = canvas::Path::line(
+                            Point::new(0.0, size * 0.6),
+                            Point::new(0.0, size * 1.8),
+                        );
+                        frame.stroke(
+                            &string,
+                            canvas::Stroke::default()
+                                .with_color(Color::from_rgba(0.9, 0.9, 0.9, color.a))
+                                .with_width(1.5),
+                        );
+                        frame.scale_nonuniform(Vector::new(0.85, 1.0));
+                        frame.fill(&canvas::Path::circle(Point::ORIGIN, size * 0.6), color);
+                    }
+                    ParticleShape::Star => {
+                        frame.fill(&Self::star_path(size), color);
+                    }
+                }
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Canvas program that draws discovered words flying from the typed letters
+/// up to the discovered-words strip at the top of the screen
+struct FlyingWordsOverlay {
+    /// Each word's text, color and flight progress from 0.0 to 1.0
+    words: Vec<(String, Color, f32)>,
+}
+
+impl<Message> canvas::Program<Message> for FlyingWordsOverlay {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let center = frame.center();
+        let count = self.words.len() as f32;
+
+        for (i, (word, color, progress)) in self.words.iter().enumerate() {
+            // Words found together spread out side by side as they rise
+            let spread = (i as f32 - (count - 1.0) / 2.0) * 220.0;
+            let start = Point::new(center.x, center.y + 60.0);
+            let end = Point::new(center.x + spread, 70.0);
+            let position = Point::new(
+                start.x + (end.x - start.x) * progress,
+                start.y + (end.y - start.y) * progress,
+            );
+
+            frame.fill_text(canvas::Text {
+                content: word.clone(),
+                position,
+                color: *color,
+                size: (120.0 - 80.0 * progress).into(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Default::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Canvas program that draws daily challenge accuracy as a bar chart
+struct AccuracyChart {
+    points: Vec<(chrono::NaiveDate, f32)>,
+}
+
+impl<Message> canvas::Program<Message> for AccuracyChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        let label_height = 24.0;
+        let chart_height = bounds.height - label_height;
+        let slot_width = bounds.width / self.points.len().max(1) as f32;
+        let bar_width = (slot_width * 0.6).min(40.0);
+
+        for (i, (day, accuracy)) in self.points.iter().enumerate() {
+            let bar_height = (chart_height * accuracy).max(2.0);
+            let x = i as f32 * slot_width + (slot_width - bar_width) / 2.0;
+
+            // Red for low accuracy through to green for high
+            let (r, g, b) = hsl_to_rgb(accuracy * 120.0, 0.7, 0.55);
+            frame.fill_rectangle(
+                Point::new(x, chart_height - bar_height),
+                iced::Size::new(bar_width, bar_height),
+                Color::from_rgb(r, g, b),
+            );
+
+            frame.fill_text(canvas::Text {
+                content: day.format("%m/%d").to_string(),
+                position: Point::new(x + bar_width / 2.0, chart_height + 4.0),
+                color: Color::from_rgb(0.5, 0.5, 0.6),
+                size: 14.0.into(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::DictionarySettings;
+
+    fn find_words(text: &str) -> (Vec<String>, Option<String>) {
+        let dictionary = Dictionary::load(&DictionarySettings::default());
+        WordsWithToddlers::find_words(&dictionary, text)
+    }
+
+    #[test]
+    fn near_misses_are_suggested_even_when_smaller_words_are_found() {
+        let (found, suggestion) = find_words("dgo");
+        assert_eq!(found, ["go"]);
+        assert_eq!(suggestion.as_deref(), Some("dog"));
+
+        let (_, suggestion) = find_words("baall");
+        assert_eq!(suggestion.as_deref(), Some("ball"));
+    }
+
+    #[test]
+    fn words_already_found_are_not_suggested() {
+        assert_eq!(find_words("catt"), (vec!["cat".to_string()], None));
+    }
+
+    #[test]
+    fn whole_words_get_no_suggestion() {
+        assert_eq!(find_words("dog"), (vec!["dog".to_string()], None));
+        assert_eq!(
+            find_words("dogcat"),
+            (vec!["dog".to_string(), "cat".to_string()], None)
+        );
+    }
+}
@@ -1,67 +1,548 @@
-use rodio::{Decoder, OutputStream, Sink};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::io::Cursor;
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
-};
-
-/// Plays a sound effect if no sound is currently playing
-pub fn play_sound(sound_playing: Arc<AtomicBool>, sound_path: String) {
-    // Check if sound is already playing
-    if sound_playing.load(Ordering::SeqCst) {
-        return;
-    }
-
-    // Set flag to true
-    sound_playing.store(true, Ordering::SeqCst);
-
-    // Spawn background thread to play sound
-    std::thread::spawn(move || {
-        // Load the sound file into memory
-        let bytes = match std::fs::read(&sound_path) {
-            Ok(b) => b,
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Sample rate of audio produced by `Sound::Synthesized`
+pub const SYNTH_SAMPLE_RATE: u32 = 22_050;
+
+/// Fraction of its volume music drops to while speech or a celebration plays
+const DUCKED_MUSIC_LEVEL: f32 = 0.25;
+
+/// How often music ducking is updated while music plays
+const DUCKING_TICK: Duration = Duration::from_millis(30);
+
+/// How far the ducking level moves each tick, so changes fade rather than jump
+const DUCKING_STEP: f32 = 0.08;
+
+/// Audio formats the audio engine can decode
+const SUPPORTED_EXTENSIONS: &[&str] = &["wav", "ogg", "flac"];
+
+/// Checks whether a file's extension is one the audio engine can decode
+pub fn is_supported_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// A group of sounds that share a volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// Short sound effects, which may overlap each other
+    Effects,
+    /// Spoken words, played one after another
+    Speech,
+    /// Background music, looped
+    Music,
+}
+
+/// Per-channel volumes stored in the config file, where 1.0 is full volume
+///
+/// Speech volume lives in the speech settings alongside the voice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioSettings {
+    #[serde(default = "default_effects_volume")]
+    pub effects_volume: f32,
+    #[serde(default = "default_music_volume")]
+    pub music_volume: f32,
+    /// Id of the looping background track; `None` plays no music
+    #[serde(default)]
+    pub music_track: Option<String>,
+}
+
+fn default_effects_volume() -> f32 {
+    1.0
+}
+
+fn default_music_volume() -> f32 {
+    0.5
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            effects_volume: default_effects_volume(),
+            music_volume: default_music_volume(),
+            music_track: None,
+        }
+    }
+}
+
+/// Where a sound's encoded audio comes from
+#[derive(Clone)]
+pub enum Sound {
+    /// Audio compiled into the binary, identified by a unique name
+    Embedded {
+        name: &'static str,
+        bytes: &'static [u8],
+    },
+    /// An audio file on disk
+    File(PathBuf),
+    /// Encoded audio held in memory, like speech rendered without a cache;
+    /// equal only to its own clones
+    Buffer(Arc<[u8]>),
+    /// Mono audio at `SYNTH_SAMPLE_RATE` computed when first played,
+    /// identified by a unique name
+    Synthesized {
+        name: &'static str,
+        render: fn() -> Vec<i16>,
+    },
+}
+
+impl Sound {
+    /// Reads the encoded audio
+    fn bytes(&self) -> Result<Vec<u8>, String> {
+        match self {
+            Sound::Embedded { bytes, .. } => Ok(bytes.to_vec()),
+            Sound::File(path) => std::fs::read(path).map_err(|e| e.to_string()),
+            Sound::Buffer(bytes) => Ok(bytes.to_vec()),
+            Sound::Synthesized { name, .. } => Err(format!("{} has no encoded audio", name)),
+        }
+    }
+}
+
+// Embedded sounds are compared by name so neither the preload cache nor
+// error messages ever touch their contents
+impl PartialEq for Sound {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Sound::Embedded { name: a, .. }, Sound::Embedded { name: b, .. }) => a == b,
+            (Sound::File(a), Sound::File(b)) => a == b,
+            (Sound::Buffer(a), Sound::Buffer(b)) => Arc::ptr_eq(a, b),
+            (Sound::Synthesized { name: a, .. }, Sound::Synthesized { name: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Sound {}
+
+impl Hash for Sound {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Sound::Embedded { name, .. } => name.hash(state),
+            Sound::File(path) => path.hash(state),
+            Sound::Buffer(bytes) => bytes.as_ptr().hash(state),
+            Sound::Synthesized { name, .. } => name.hash(state),
+        }
+    }
+}
+
+impl fmt::Debug for Sound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sound::Embedded { name, .. } => write!(f, "built-in sound {:?}", name),
+            Sound::File(path) => write!(f, "{:?}", path),
+            Sound::Buffer(bytes) => write!(f, "{} bytes of audio", bytes.len()),
+            Sound::Synthesized { name, .. } => write!(f, "synthesized sound {:?}", name),
+        }
+    }
+}
+
+/// A sound decoded into memory, ready to play without touching the disk
+///
+/// The samples are shared, so playing the sound again never copies them.
+#[derive(Clone)]
+struct DecodedSound {
+    channels: u16,
+    sample_rate: u32,
+    samples: Arc<[i16]>,
+}
+
+impl DecodedSound {
+    /// Reads and decodes a sound
+    fn load(sound: &Sound) -> Result<Self, String> {
+        if let Sound::Synthesized { render, .. } = sound {
+            return Ok(DecodedSound {
+                channels: 1,
+                sample_rate: SYNTH_SAMPLE_RATE,
+                samples: render().into(),
+            });
+        }
+
+        let bytes = sound.bytes()?;
+        let decoder = Decoder::new(Cursor::new(bytes)).map_err(|e| e.to_string())?;
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+        Ok(DecodedSound {
+            channels,
+            sample_rate,
+            samples: decoder.collect(),
+        })
+    }
+
+    /// Returns a source that plays the sound from the start
+    fn source(&self) -> DecodedSource {
+        DecodedSource {
+            sound: self.clone(),
+            position: 0,
+        }
+    }
+}
+
+/// Plays a `DecodedSound` without copying its samples
+struct DecodedSource {
+    sound: DecodedSound,
+    position: usize,
+}
+
+impl Iterator for DecodedSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let sample = self.sound.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.sound.samples.len().saturating_sub(self.position);
+        (remaining, Some(remaining))
+    }
+}
+
+impl Source for DecodedSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        // The format never changes, so the whole sound is one frame
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.sound.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sound.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        let frames = self.sound.samples.len() as f64 / self.sound.channels.max(1) as f64;
+        Some(Duration::from_secs_f64(
+            frames / self.sound.sample_rate.max(1) as f64,
+        ))
+    }
+}
+
+/// Commands handled by the audio thread
+#[derive(Debug)]
+pub(crate) enum AudioCommand {
+    Preload(Vec<Sound>),
+    PlayEffect { sound: Sound, duck_music: bool },
+    PlaySpeech { sound: Sound, interrupt: bool },
+    PlayMusic(Sound),
+    PauseMusic(bool),
+    DuckMusic(bool),
+    Stop(Channel),
+    SetVolume(Channel, f32),
+}
+
+/// Handle to the app's audio engine
+///
+/// One output stream lives on its own thread for the life of the app and
+/// mixes every channel. Effects overlap freely, speech clips queue behind
+/// each other and music loops until stopped, ducking under speech and
+/// celebration sounds. Sounds passed to `preload` are decoded once and played
+/// from memory after that.
+#[derive(Clone)]
+pub struct AudioEngine {
+    commands: Sender<AudioCommand>,
+}
+
+impl AudioEngine {
+    /// Starts the audio thread
+    pub fn start(settings: &AudioSettings) -> Self {
+        let (commands, receiver) = mpsc::channel();
+        std::thread::spawn(move || AudioThread::run(receiver));
+
+        let engine = AudioEngine { commands };
+        engine.apply_settings(settings);
+        engine
+    }
+
+    /// Decodes sounds into memory in the background so they start instantly
+    pub fn preload(&self, sounds: impl IntoIterator<Item = Sound>) {
+        let sounds = sounds.into_iter().collect();
+        let _ = self.commands.send(AudioCommand::Preload(sounds));
+    }
+
+    /// Plays a sound effect on top of anything else that is playing
+    pub fn play_effect(&self, sound: Sound) {
+        let _ = self.commands.send(AudioCommand::PlayEffect {
+            sound,
+            duck_music: false,
+        });
+    }
+
+    /// Plays a sound effect that the background music ducks under
+    pub fn play_celebration(&self, sound: Sound) {
+        let _ = self.commands.send(AudioCommand::PlayEffect {
+            sound,
+            duck_music: true,
+        });
+    }
+
+    /// Plays a speech clip, either after whatever is queued or cutting it off
+    pub fn play_speech(&self, sound: Sound, interrupt: bool) {
+        let _ = self
+            .commands
+            .send(AudioCommand::PlaySpeech { sound, interrupt });
+    }
+
+    /// Loops a music track, replacing any track already playing
+    pub fn play_music(&self, sound: Sound) {
+        let _ = self.commands.send(AudioCommand::PlayMusic(sound));
+    }
+
+    /// Pauses or resumes the music track where it left off
+    pub fn pause_music(&self, paused: bool) {
+        let _ = self.commands.send(AudioCommand::PauseMusic(paused));
+    }
+
+    /// Keeps the music ducked while set, for speech played outside the engine
+    pub fn duck_music(&self, ducked: bool) {
+        let _ = self.commands.send(AudioCommand::DuckMusic(ducked));
+    }
+
+    /// Stops everything playing or queued on a channel
+    pub fn stop(&self, channel: Channel) {
+        let _ = self.commands.send(AudioCommand::Stop(channel));
+    }
+
+    /// Sets a channel's volume, where 1.0 is the recorded level
+    pub fn set_volume(&self, channel: Channel, volume: f32) {
+        let _ = self
+            .commands
+            .send(AudioCommand::SetVolume(channel, volume.max(0.0)));
+    }
+
+    /// Applies the configured effects and music volumes
+    pub fn apply_settings(&self, settings: &AudioSettings) {
+        self.set_volume(Channel::Effects, settings.effects_volume);
+        self.set_volume(Channel::Music, settings.music_volume);
+    }
+
+    /// Creates an engine without an audio thread, along with the commands
+    /// sent to it, so tests can check what would have been played
+    #[cfg(test)]
+    pub(crate) fn detached() -> (Self, Receiver<AudioCommand>) {
+        let (commands, receiver) = mpsc::channel();
+        (AudioEngine { commands }, receiver)
+    }
+}
+
+/// State owned by the audio thread
+struct AudioThread {
+    // Keeps the output device open; dropping it silences every sink
+    _stream: OutputStream,
+    stream_handle: OutputStreamHandle,
+    sounds: HashMap<Sound, DecodedSound>,
+    /// Playing effects, and whether the music ducks under each
+    effects: Vec<(Sink, bool)>,
+    speech: Sink,
+    music: Sink,
+    volumes: HashMap<Channel, f32>,
+    /// Whether speech is playing outside the engine
+    live_speech: bool,
+    /// Current music level relative to the music volume, 1.0 when not ducked
+    music_duck_level: f32,
+}
+
+impl AudioThread {
+    /// Opens the output stream and applies commands as they arrive
+    fn run(receiver: Receiver<AudioCommand>) {
+        let mut thread = match Self::open() {
+            Ok(thread) => thread,
             Err(e) => {
-                eprintln!("Failed to read sound file {}: {}", sound_path, e);
-                sound_playing.store(false, Ordering::SeqCst);
+                eprintln!("Audio unavailable: {}", e);
                 return;
             }
         };
 
-        // Create decoder from memory (Cursor provides seekable source)
-        let cursor = Cursor::new(bytes);
-        let source = match Decoder::new(cursor) {
-            Ok(s) => s,
-            Err(e) => {
-                eprintln!("Failed to decode sound file: {}", e);
-                sound_playing.store(false, Ordering::SeqCst);
-                return;
+        loop {
+            // Wake up regularly while music plays so ducking can follow speech
+            let command = if thread.music.empty() || thread.music.is_paused() {
+                receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                receiver.recv_timeout(DUCKING_TICK)
+            };
+            match command {
+                Ok(command) => thread.handle(command),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
             }
+            thread.update_ducking();
+        }
+    }
+
+    fn open() -> Result<Self, String> {
+        let (stream, stream_handle) = OutputStream::try_default()
+            .map_err(|e| format!("failed to create audio output stream: {}", e))?;
+        let new_sink = || {
+            Sink::try_new(&stream_handle).map_err(|e| format!("failed to create audio sink: {}", e))
         };
+        let speech = new_sink()?;
+        let music = new_sink()?;
 
-        // Create output stream and sink
-        let (_stream, stream_handle) = match OutputStream::try_default() {
-            Ok(s) => s,
-            Err(e) => {
-                eprintln!("Failed to create audio output stream: {}", e);
-                sound_playing.store(false, Ordering::SeqCst);
-                return;
+        Ok(AudioThread {
+            speech,
+            music,
+            _stream: stream,
+            stream_handle,
+            sounds: HashMap::new(),
+            effects: Vec::new(),
+            volumes: HashMap::new(),
+            live_speech: false,
+            music_duck_level: 1.0,
+        })
+    }
+
+    fn handle(&mut self, command: AudioCommand) {
+        match command {
+            AudioCommand::Preload(sounds) => {
+                for sound in sounds {
+                    if self.sounds.contains_key(&sound) {
+                        continue;
+                    }
+                    match DecodedSound::load(&sound) {
+                        Ok(decoded) => {
+                            self.sounds.insert(sound, decoded);
+                        }
+                        Err(e) => eprintln!("Failed to preload sound {:?}: {}", sound, e),
+                    }
+                }
+            }
+            AudioCommand::PlayEffect { sound, duck_music } => {
+                // Effects get a sink each so they can overlap; finished ones are dropped
+                self.effects.retain(|(sink, _)| !sink.empty());
+                let Some(source) = self.source(&sound) else {
+                    return;
+                };
+                match Sink::try_new(&self.stream_handle) {
+                    Ok(sink) => {
+                        sink.set_volume(self.volume(Channel::Effects));
+                        sink.append(source);
+                        self.effects.push((sink, duck_music));
+                    }
+                    Err(e) => eprintln!("Failed to create audio sink: {}", e),
+                }
+            }
+            AudioCommand::PlaySpeech { sound, interrupt } => {
+                let Some(source) = self.source(&sound) else {
+                    return;
+                };
+                if interrupt {
+                    self.speech.clear();
+                }
+                self.speech.append(source);
+                self.speech.play();
+            }
+            AudioCommand::PlayMusic(sound) => {
+                let Some(source) = self.source(&sound) else {
+                    return;
+                };
+                self.music.clear();
+                self.music.append(source.repeat_infinite());
+                self.music.play();
             }
+            AudioCommand::PauseMusic(true) => self.music.pause(),
+            AudioCommand::PauseMusic(false) => self.music.play(),
+            AudioCommand::DuckMusic(ducked) => self.live_speech = ducked,
+            AudioCommand::Stop(channel) => match channel {
+                Channel::Effects => self.effects.clear(),
+                Channel::Speech => self.speech.clear(),
+                Channel::Music => self.music.clear(),
+            },
+            AudioCommand::SetVolume(channel, volume) => {
+                self.volumes.insert(channel, volume);
+                match channel {
+                    Channel::Effects => self.effects.iter().for_each(|(s, _)| s.set_volume(volume)),
+                    Channel::Speech => self.speech.set_volume(volume),
+                    Channel::Music => self.music.set_volume(volume * self.music_duck_level),
+                }
+            }
+        }
+    }
+
+    /// Moves the music level a step toward ducked while speech or a
+    /// celebration is playing, and back toward full volume otherwise
+    fn update_ducking(&mut self) {
+        let should_duck = self.live_speech
+            || !self.speech.empty()
+            || self
+                .effects
+                .iter()
+                .any(|(sink, duck_music)| *duck_music && !sink.empty());
+        let target = if should_duck { DUCKED_MUSIC_LEVEL } else { 1.0 };
+        if self.music_duck_level == target {
+            return;
+        }
+
+        self.music_duck_level = if self.music_duck_level < target {
+            (self.music_duck_level + DUCKING_STEP).min(target)
+        } else {
+            (self.music_duck_level - DUCKING_STEP).max(target)
         };
+        self.music
+            .set_volume(self.volume(Channel::Music) * self.music_duck_level);
+    }
+
+    fn volume(&self, channel: Channel) -> f32 {
+        self.volumes.get(&channel).copied().unwrap_or(1.0)
+    }
 
-        let sink = match Sink::try_new(&stream_handle) {
-            Ok(s) => s,
+    /// Returns a playable copy of a preloaded sound, or decodes it now
+    fn source(&self, sound: &Sound) -> Option<DecodedSource> {
+        if let Some(decoded) = self.sounds.get(sound) {
+            return Some(decoded.source());
+        }
+        match DecodedSound::load(sound) {
+            Ok(decoded) => Some(decoded.source()),
             Err(e) => {
-                eprintln!("Failed to create audio sink: {}", e);
-                sound_playing.store(false, Ordering::SeqCst);
-                return;
+                eprintln!("Failed to load sound {:?}: {}", sound, e);
+                None
             }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn beep() -> Vec<i16> {
+        vec![100; SYNTH_SAMPLE_RATE as usize / 2]
+    }
+
+    #[test]
+    fn decoded_sounds_play_without_copying() {
+        let sound = Sound::Synthesized {
+            name: "beep",
+            render: beep,
         };
+        let decoded = DecodedSound::load(&sound).unwrap();
 
-        // Play the sound
-        sink.append(source);
-        sink.sleep_until_end();
+        let first = decoded.source();
+        let second = decoded.source();
 
-        // Reset flag when done
-        sound_playing.store(false, Ordering::SeqCst);
-    });
+        assert!(Arc::ptr_eq(&first.sound.samples, &second.sound.samples));
+        assert_eq!(first.total_duration(), Some(Duration::from_millis(500)));
+        assert_eq!(first.size_hint(), (beep().len(), Some(beep().len())));
+        assert_eq!(second.collect::<Vec<i16>>(), beep());
+    }
+
+    #[test]
+    fn buffers_are_equal_only_to_their_clones() {
+        let bytes: Arc<[u8]> = Arc::from(&b"RIFF"[..]);
+        let sound = Sound::Buffer(bytes.clone());
+
+        assert_eq!(sound, sound.clone());
+        assert_ne!(sound, Sound::Buffer(Arc::from(&b"RIFF"[..])));
+        assert_eq!(sound.bytes().unwrap(), b"RIFF");
+    }
 }
@@ -1,28 +1,179 @@
-use std::time::{Duration, Instant};
+use crate::config::ColorPalette;
+use crate::utils::color::hsl_to_rgb;
+use iced::Color;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{PI, TAU};
 
+/// How long a celebration lasts, in seconds
+pub const CELEBRATION_SECS: f32 = 1.5;
+
+/// Downward acceleration in pixels per second squared
+const GRAVITY: f32 = 900.0;
+
+/// The kind of animation played for a correct word
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CelebrationStyle {
+    #[default]
+    Confetti,
+    Fireworks,
+    Balloons,
+    Stars,
+}
+
+impl CelebrationStyle {
+    /// Returns the label shown on the settings button
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CelebrationStyle::Confetti => "Confetti",
+            CelebrationStyle::Fireworks => "Fireworks",
+            CelebrationStyle::Balloons => "Balloons",
+            CelebrationStyle::Stars => "Stars",
+        }
+    }
+
+    /// Returns all available celebration styles
+    pub fn all() -> &'static [CelebrationStyle] {
+        &[
+            CelebrationStyle::Confetti,
+            CelebrationStyle::Fireworks,
+            CelebrationStyle::Balloons,
+            CelebrationStyle::Stars,
+        ]
+    }
+
+    /// Number of particles at intensity 1.0
+    fn base_particles(&self) -> usize {
+        match self {
+            CelebrationStyle::Confetti => 60,
+            CelebrationStyle::Fireworks => 48,
+            CelebrationStyle::Balloons => 8,
+            CelebrationStyle::Stars => 20,
+        }
+    }
+}
+
+/// What a particle looks like
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticleShape {
+    /// A paper rectangle, twice as wide as it is tall
+    Confetti,
+    /// A glowing dot
+    Spark,
+    /// An oval balloon on a string
+    Balloon,
+    /// A five-pointed star
+    Star,
+}
+
+/// One piece of a celebration
+///
+/// Positions are in pixels relative to the point the celebration bursts
+/// from, with y growing downward.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+    pub shape: ParticleShape,
+    pub x: f32,
+    pub y: f32,
+    vx: f32,
+    vy: f32,
+    /// Fraction of velocity lost per second to air resistance
+    drag: f32,
+    /// Multiplier on gravity; negative values float upward
+    gravity: f32,
+    pub size: f32,
+    pub rotation: f32,
+    spin: f32,
+    pub color: Color,
+    /// Seconds after the celebration starts before the particle appears
+    delay: f32,
+    /// Seconds the particle stays visible once it appears
+    lifetime: f32,
+    age: f32,
+}
+
+impl Particle {
+    /// Checks whether the particle should be drawn yet
+    pub fn is_visible(&self) -> bool {
+        self.age > 0.0 && self.age < self.lifetime
+    }
+
+    /// Returns how opaque the particle is, fading out over its last third
+    pub fn opacity(&self) -> f32 {
+        let remaining = 1.0 - self.age / self.lifetime;
+        (remaining * 3.0).clamp(0.0, 1.0)
+    }
+
+    fn step(&mut self, dt: f32) {
+        self.age += dt;
+        if self.age <= 0.0 {
+            return;
+        }
+        let damping = (1.0 - self.drag * dt).max(0.0);
+        self.vx *= damping;
+        self.vy = self.vy * damping + GRAVITY * self.gravity * dt;
+        self.x += self.vx * dt;
+        self.y += self.vy * dt;
+        self.rotation += self.spin * dt;
+    }
+}
+
+/// A particle animation played when a word is typed correctly
+///
+/// All randomness comes from the seed, and time only moves forward through
+/// `update`, so the same seed and updates always produce the same frames.
 #[derive(Debug, Clone)]
 pub struct Celebration {
-    start_time: Instant,
-    duration: Duration,
+    particles: Vec<Particle>,
+    elapsed: f32,
 }
 
 impl Celebration {
-    pub fn new() -> Self {
+    /// Creates a celebration whose particle count grows with the streak of
+    /// words answered correctly in a row
+    pub fn new(style: CelebrationStyle, streak: usize, palette: &ColorPalette, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let count = (style.base_particles() as f32 * intensity_for_streak(streak)).round() as usize;
+        let colors = PaletteColors::new(palette);
+
+        let mut particles: Vec<Particle> = match style {
+            CelebrationStyle::Confetti => (0..count).map(|_| confetti(&mut rng, &colors)).collect(),
+            CelebrationStyle::Fireworks => fireworks(&mut rng, &colors, count),
+            CelebrationStyle::Balloons => (0..count).map(|_| balloon(&mut rng, &colors)).collect(),
+            CelebrationStyle::Stars => (0..count).map(|_| star(&mut rng, &colors)).collect(),
+        };
+        // Delayed particles start the clock early and never outlast the celebration
+        for p in &mut particles {
+            p.age = -p.delay;
+            p.lifetime = p.lifetime.min(CELEBRATION_SECS - p.delay);
+        }
+
         Celebration {
-            start_time: Instant::now(),
-            duration: Duration::from_millis(1500),
+            particles,
+            elapsed: 0.0,
         }
     }
 
-    #[allow(dead_code)]
+    /// Advances the animation by `dt` seconds
+    pub fn update(&mut self, dt: f32) {
+        self.elapsed += dt;
+        for particle in &mut self.particles {
+            particle.step(dt);
+        }
+    }
+
+    /// Returns the particles that should be drawn now
+    pub fn visible_particles(&self) -> impl Iterator<Item = &Particle> {
+        self.particles.iter().filter(|p| p.is_visible())
+    }
+
     pub fn is_active(&self) -> bool {
-        self.start_time.elapsed() < self.duration
+        self.elapsed < CELEBRATION_SECS
     }
 
     pub fn progress(&self) -> f32 {
-        let elapsed = self.start_time.elapsed().as_secs_f32();
-        let total = self.duration.as_secs_f32();
-        (elapsed / total).min(1.0)
+        (self.elapsed / CELEBRATION_SECS).min(1.0)
     }
 
     pub fn scale_factor(&self) -> f32 {
@@ -46,8 +197,213 @@ impl Celebration {
     }
 }
 
-impl Default for Celebration {
-    fn default() -> Self {
-        Self::new()
+/// Scales particle counts with a streak: 1x for a single word, rising to 3x
+/// at a streak of nine or more
+pub fn intensity_for_streak(streak: usize) -> f32 {
+    1.0 + streak.saturating_sub(1).min(8) as f32 * 0.25
+}
+
+/// Picks particle colors from the child's letter color palette
+struct PaletteColors {
+    hue_min: f32,
+    hue_max: f32,
+    saturation: f32,
+    lightness: f32,
+}
+
+impl PaletteColors {
+    fn new(palette: &ColorPalette) -> Self {
+        let (hue_min, hue_max, saturation, lightness) = palette.color_params();
+        PaletteColors {
+            hue_min,
+            hue_max,
+            saturation,
+            lightness,
+        }
+    }
+
+    fn pick(&self, rng: &mut StdRng) -> Color {
+        let hue = rng.gen_range(self.hue_min..self.hue_max);
+        let (r, g, b) = hsl_to_rgb(hue, self.saturation, self.lightness);
+        Color::from_rgb(r, g, b)
+    }
+}
+
+/// A particle with no motion of its own, for the builders below to fill in
+fn particle(shape: ParticleShape, color: Color, size: f32, lifetime: f32) -> Particle {
+    Particle {
+        shape,
+        x: 0.0,
+        y: 0.0,
+        vx: 0.0,
+        vy: 0.0,
+        drag: 0.0,
+        gravity: 1.0,
+        size,
+        rotation: 0.0,
+        spin: 0.0,
+        color,
+        delay: 0.0,
+        lifetime,
+        age: 0.0,
+    }
+}
+
+/// Paper thrown upward in a wide cone that tumbles as it falls
+fn confetti(rng: &mut StdRng, colors: &PaletteColors) -> Particle {
+    let angle = -PI / 2.0 + rng.gen_range(-0.9..0.9);
+    let speed = rng.gen_range(450.0..900.0);
+    let mut p = particle(
+        ParticleShape::Confetti,
+        colors.pick(rng),
+        rng.gen_range(8.0..14.0),
+        CELEBRATION_SECS,
+    );
+    p.vx = angle.cos() * speed;
+    p.vy = angle.sin() * speed;
+    p.drag = 1.5;
+    p.rotation = rng.gen_range(0.0..TAU);
+    p.spin = rng.gen_range(-12.0..12.0);
+    p.delay = rng.gen_range(0.0..0.1);
+    p
+}
+
+/// Several shells, each bursting into a ring of sparks at its own point
+fn fireworks(rng: &mut StdRng, colors: &PaletteColors, count: usize) -> Vec<Particle> {
+    let shells = (count / 16).max(1);
+    let per_shell = count / shells;
+    let mut particles = Vec::with_capacity(count);
+
+    for shell in 0..shells {
+        let center_x = rng.gen_range(-350.0..350.0);
+        let center_y = rng.gen_range(-250.0..-50.0);
+        let delay = shell as f32 / shells as f32 * 0.5;
+        let color = colors.pick(rng);
+        for i in 0..per_shell {
+            let angle = i as f32 / per_shell as f32 * TAU + rng.gen_range(-0.1..0.1);
+            let speed = rng.gen_range(250.0..380.0);
+            let mut p = particle(ParticleShape::Spark, color, rng.gen_range(3.0..5.0), 0.9);
+            p.x = center_x;
+            p.y = center_y;
+            p.vx = angle.cos() * speed;
+            p.vy = angle.sin() * speed;
+            p.drag = 2.5;
+            p.gravity = 0.3;
+            p.delay = delay;
+            particles.push(p);
+        }
+    }
+    particles
+}
+
+/// Balloons that float up from below the word, drifting sideways
+fn balloon(rng: &mut StdRng, colors: &PaletteColors) -> Particle {
+    let mut p = particle(
+        ParticleShape::Balloon,
+        colors.pick(rng),
+        rng.gen_range(28.0..40.0),
+        CELEBRATION_SECS,
+    );
+    p.x = rng.gen_range(-400.0..400.0);
+    p.y = rng.gen_range(150.0..300.0);
+    p.vx = rng.gen_range(-40.0..40.0);
+    p.vy = rng.gen_range(-260.0..-180.0);
+    p.gravity = -0.15;
+    p.rotation = rng.gen_range(-0.2..0.2);
+    p.delay = rng.gen_range(0.0..0.3);
+    p
+}
+
+/// Stars bursting outward from the word, spinning as they slow down
+fn star(rng: &mut StdRng, colors: &PaletteColors) -> Particle {
+    let angle = rng.gen_range(0.0..TAU);
+    let speed = rng.gen_range(300.0..600.0);
+    let mut p = particle(
+        ParticleShape::Star,
+        colors.pick(rng),
+        rng.gen_range(12.0..22.0),
+        rng.gen_range(1.0..CELEBRATION_SECS),
+    );
+    p.vx = angle.cos() * speed;
+    p.vy = angle.sin() * speed;
+    p.drag = 2.0;
+    p.gravity = 0.2;
+    p.spin = rng.gen_range(-6.0..6.0);
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(celebration: &mut Celebration, frames: usize) {
+        for _ in 0..frames {
+            celebration.update(1.0 / 60.0);
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_animation() {
+        let palette = ColorPalette::Rainbow;
+        for &style in CelebrationStyle::all() {
+            let mut a = Celebration::new(style, 3, &palette, 42);
+            let mut b = Celebration::new(style, 3, &palette, 42);
+            run(&mut a, 30);
+            run(&mut b, 30);
+            assert_eq!(a.particles, b.particles, "{:?}", style);
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_animations() {
+        let palette = ColorPalette::Rainbow;
+        let a = Celebration::new(CelebrationStyle::Confetti, 1, &palette, 1);
+        let b = Celebration::new(CelebrationStyle::Confetti, 1, &palette, 2);
+        assert_ne!(a.particles, b.particles);
+    }
+
+    #[test]
+    fn longer_streaks_add_particles() {
+        let palette = ColorPalette::Rainbow;
+        let single = Celebration::new(CelebrationStyle::Stars, 1, &palette, 7);
+        let streak = Celebration::new(CelebrationStyle::Stars, 5, &palette, 7);
+        let capped = Celebration::new(CelebrationStyle::Stars, 50, &palette, 7);
+        assert!(streak.particles.len() > single.particles.len());
+        assert_eq!(
+            capped.particles.len(),
+            (single.particles.len() as f32 * intensity_for_streak(9)) as usize
+        );
+    }
+
+    #[test]
+    fn confetti_rises_then_falls() {
+        let mut celebration =
+            Celebration::new(CelebrationStyle::Confetti, 1, &ColorPalette::Rainbow, 3);
+        let mean_vy = |c: &Celebration| {
+            c.particles.iter().map(|p| p.vy).sum::<f32>() / c.particles.len() as f32
+        };
+
+        run(&mut celebration, 12);
+        assert!(
+            mean_vy(&celebration) < 0.0,
+            "confetti should start by moving up"
+        );
+        run(&mut celebration, 48);
+        assert!(
+            mean_vy(&celebration) > 0.0,
+            "gravity should pull confetti down"
+        );
+    }
+
+    #[test]
+    fn particles_are_gone_when_the_celebration_ends() {
+        for &style in CelebrationStyle::all() {
+            let mut celebration = Celebration::new(style, 9, &ColorPalette::Neon, 11);
+            assert!(celebration.is_active());
+            run(&mut celebration, (CELEBRATION_SECS * 60.0) as usize + 1);
+            assert!(!celebration.is_active());
+            assert_eq!(celebration.visible_particles().count(), 0, "{:?}", style);
+            assert_eq!(celebration.progress(), 1.0);
+        }
     }
 }
@@ -1,7 +1,115 @@
+use crate::audio::AudioSettings;
+use crate::dictionary::DictionarySettings;
 use crate::grade_level::GradeLevel;
+use crate::profile::{self, Profile, DEFAULT_PROFILE_ID};
+use crate::sound_scheme::{SoundEvent, SoundScheme};
+use crate::speech::SpeechSettings;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// How letters and words are displayed
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum LetterCase {
+    #[default]
+    Uppercase,
+    Lowercase,
+    /// Shows each letter exactly as it was typed
+    AsTyped,
+    /// Shows the uppercase and lowercase forms together, e.g. "Aa"
+    Both,
+}
+
+impl LetterCase {
+    /// Returns the label shown on the settings button
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LetterCase::Uppercase => "ABC",
+            LetterCase::Lowercase => "abc",
+            LetterCase::AsTyped => "As Typed",
+            LetterCase::Both => "Aa",
+        }
+    }
+
+    /// Formats a single letter for display
+    pub fn format_char(&self, c: char) -> String {
+        match self {
+            LetterCase::Uppercase => c.to_uppercase().collect(),
+            LetterCase::Lowercase => c.to_lowercase().collect(),
+            LetterCase::AsTyped => c.to_string(),
+            LetterCase::Both if c.is_alphabetic() => {
+                c.to_uppercase().chain(c.to_lowercase()).collect()
+            }
+            LetterCase::Both => c.to_string(),
+        }
+    }
+
+    /// Formats a whole word for display; "both" shows the uppercase word
+    /// followed by the lowercase one
+    pub fn format_word(&self, word: &str) -> String {
+        match self {
+            LetterCase::Uppercase => word.to_uppercase(),
+            LetterCase::Lowercase => word.to_lowercase(),
+            LetterCase::AsTyped => word.to_string(),
+            LetterCase::Both => format!("{} {}", word.to_uppercase(), word.to_lowercase()),
+        }
+    }
+
+    /// Returns all available letter cases
+    pub fn all() -> &'static [LetterCase] {
+        &[
+            LetterCase::Uppercase,
+            LetterCase::Lowercase,
+            LetterCase::AsTyped,
+            LetterCase::Both,
+        ]
+    }
+}
+
+/// Whether and how Discovery mode sounds out typed letters
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum PhonicsMode {
+    #[default]
+    Off,
+    /// Says each letter's name ("bee")
+    LetterNames,
+    /// Says each letter's sound ("buh")
+    LetterSounds,
+}
+
+impl PhonicsMode {
+    /// Returns the label shown on the settings button
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PhonicsMode::Off => "Off",
+            PhonicsMode::LetterNames => "Names",
+            PhonicsMode::LetterSounds => "Sounds",
+        }
+    }
+
+    /// Returns all available phonics modes
+    pub fn all() -> &'static [PhonicsMode] {
+        &[
+            PhonicsMode::Off,
+            PhonicsMode::LetterNames,
+            PhonicsMode::LetterSounds,
+        ]
+    }
+}
+
+/// Version of the config file layout written by this build
+///
+/// Bump this and append a step to `MIGRATIONS` whenever the layout changes.
+pub const CONFIG_SCHEMA_VERSION: u32 = 3;
+
+/// A single migration step, upgrading the raw JSON by one schema version
+type Migration = fn(&mut Value) -> Result<(), Box<dyn std::error::Error>>;
+
+/// Migration steps in order; entry `i` upgrades a version `i` config to `i + 1`
+const MIGRATIONS: [Migration; CONFIG_SCHEMA_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2, migrate_v2_to_v3];
 
 /// Represents a color palette for letter colors
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -76,82 +184,577 @@ impl ColorPalette {
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
+    #[serde(default)]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub kiosk_mode_enabled: bool,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    #[serde(default)]
+    pub speech: SpeechSettings,
+    #[serde(default)]
+    pub audio: AudioSettings,
+    #[serde(default)]
+    pub dictionary: DictionarySettings,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        let mut config = AppConfig {
+            schema_version: CONFIG_SCHEMA_VERSION,
+            kiosk_mode_enabled: false,
+            profiles: Vec::new(),
+            active_profile: None,
+            speech: SpeechSettings::default(),
+            audio: AudioSettings::default(),
+            dictionary: DictionarySettings::default(),
+        };
+        config.ensure_profiles();
+        config
+    }
+}
+
+impl AppConfig {
+    /// Returns the active profile
+    pub fn active_profile(&self) -> &Profile {
+        self.active_profile
+            .as_ref()
+            .and_then(|id| self.profiles.iter().find(|p| &p.id == id))
+            .or_else(|| self.profiles.first())
+            .expect("config always has at least one profile")
+    }
+
+    /// Returns the active profile for modification
+    pub fn active_profile_mut(&mut self) -> &mut Profile {
+        let index = self
+            .active_profile
+            .as_ref()
+            .and_then(|id| self.profiles.iter().position(|p| &p.id == id))
+            .unwrap_or(0);
+        &mut self.profiles[index]
+    }
+
+    /// Ensures at least one profile exists and the active profile is valid
+    fn ensure_profiles(&mut self) {
+        if self.profiles.is_empty() {
+            self.profiles
+                .push(Profile::from_legacy_settings(&LegacySettings::default()));
+        }
+
+        let active_is_valid = self
+            .active_profile
+            .as_ref()
+            .is_some_and(|id| self.profiles.iter().any(|p| &p.id == id));
+        if !active_is_valid {
+            self.active_profile = Some(self.profiles[0].id.clone());
+        }
+    }
+}
+
+/// Display settings stored at the top level of the config before profiles existed
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegacySettings {
+    #[serde(default = "default_sound")]
     pub selected_sound: String,
     #[serde(default)]
     pub last_selected_grade: GradeLevel,
     #[serde(default = "default_uppercase")]
     pub use_uppercase: bool,
     #[serde(default)]
-    pub kiosk_mode_enabled: bool,
-    #[serde(default)]
     pub color_palette: ColorPalette,
 }
 
+impl LegacySettings {
+    /// Maps the old uppercase toggle onto a letter case
+    pub fn letter_case(&self) -> LetterCase {
+        if self.use_uppercase {
+            LetterCase::Uppercase
+        } else {
+            LetterCase::Lowercase
+        }
+    }
+}
+
+fn default_sound() -> String {
+    "Swoosh".to_string()
+}
+
 fn default_uppercase() -> bool {
     true
 }
 
-impl Default for AppConfig {
+impl Default for LegacySettings {
     fn default() -> Self {
-        AppConfig {
-            selected_sound: "Swoosh".to_string(),
+        LegacySettings {
+            selected_sound: default_sound(),
             last_selected_grade: GradeLevel::default(),
-            use_uppercase: true,
-            kiosk_mode_enabled: false,
+            use_uppercase: default_uppercase(),
             color_palette: ColorPalette::default(),
         }
     }
 }
 
-/// Gets the configuration directory path
-fn get_config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
+/// v0 -> v1: moves the top-level display settings into a default profile
+///
+/// Version 0 covers every config written before the schema version existed,
+/// including ones that already have profiles.
+fn migrate_v0_to_v1(value: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let object = value.as_object_mut().ok_or("Config is not a JSON object")?;
 
-    let config_dir = home_dir
-        .join("Library")
-        .join("Application Support")
-        .join("WordsWithToddlers");
+    let has_profiles = object
+        .get("profiles")
+        .and_then(Value::as_array)
+        .is_some_and(|profiles| !profiles.is_empty());
+    if !has_profiles {
+        let legacy: LegacySettings = serde_json::from_value(Value::Object(object.clone()))?;
+        let default_profile = Profile::from_legacy_settings(&legacy);
+        object.insert(
+            "active_profile".to_string(),
+            Value::String(default_profile.id.clone()),
+        );
+        // Version 1 profiles each carried the selected sound, which version 3
+        // turns into a sound scheme
+        let mut profile_value = serde_json::to_value(&default_profile)?;
+        if let Some(profile_object) = profile_value.as_object_mut() {
+            profile_object.remove("sound_scheme");
+        }
+        profile_value["selected_sound"] = Value::String(legacy.selected_sound.clone());
+        object.insert("profiles".to_string(), Value::Array(vec![profile_value]));
+    }
 
-    // Create directory if it doesn't exist
-    fs::create_dir_all(&config_dir)?;
+    // The grade now lives in the profile's starting grade and adaptive state
+    for key in [
+        "selected_sound",
+        "last_selected_grade",
+        "use_uppercase",
+        "color_palette",
+    ] {
+        object.remove(key);
+    }
 
-    Ok(config_dir)
+    Ok(())
 }
 
-/// Gets the full path to the config file
-fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    Ok(get_config_dir()?.join("config.json"))
+/// v1 -> v2: replaces each profile's `use_uppercase` toggle with a `letter_case`
+fn migrate_v1_to_v2(value: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(profiles) = value.get_mut("profiles").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+
+    for profile in profiles {
+        let object = profile
+            .as_object_mut()
+            .ok_or("Profile is not a JSON object")?;
+        let Some(use_uppercase) = object.remove("use_uppercase") else {
+            continue;
+        };
+        let letter_case = if use_uppercase.as_bool().unwrap_or(true) {
+            LetterCase::Uppercase
+        } else {
+            LetterCase::Lowercase
+        };
+        object
+            .entry("letter_case")
+            .or_insert(serde_json::to_value(letter_case)?);
+    }
+
+    Ok(())
 }
 
-/// Loads the application configuration
-pub fn load_config() -> AppConfig {
-    match try_load_config() {
-        Ok(config) => config,
-        Err(e) => {
-            eprintln!("Failed to load config (using defaults): {}", e);
-            AppConfig::default()
+/// v2 -> v3: replaces each profile's `selected_sound` with a sound scheme
+/// that plays it for found and correct words
+fn migrate_v2_to_v3(value: &mut Value) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(profiles) = value.get_mut("profiles").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+
+    for profile in profiles {
+        let object = profile
+            .as_object_mut()
+            .ok_or("Profile is not a JSON object")?;
+        let Some(Value::String(sound)) = object.remove("selected_sound") else {
+            continue;
+        };
+        let mut scheme = SoundScheme::default();
+        scheme.set(SoundEvent::WordDiscovered, Some(sound.clone()));
+        scheme.set(SoundEvent::WordCorrect, Some(sound));
+        object
+            .entry("sound_scheme")
+            .or_insert(serde_json::to_value(scheme)?);
+    }
+
+    Ok(())
+}
+
+/// Owns the current configuration and is the only place that writes it
+///
+/// Settings are changed through `update`, which saves the whole config
+/// atomically. The most recent load or save failure is kept so the UI can
+/// show it.
+pub struct ConfigService {
+    config: AppConfig,
+    path: Option<PathBuf>,
+    last_error: Option<String>,
+}
+
+impl ConfigService {
+    /// Loads the configuration from disk, migrating it to the current schema
+    ///
+    /// Falls back to defaults if the file can't be read; a file that can't be
+    /// parsed is kept next to the config as `config.json.bad`. Settings saved
+    /// by a newer version are never overwritten, so changes aren't saved.
+    pub fn load() -> Self {
+        match get_config_path() {
+            Ok(path) => Self::load_from(path),
+            Err(e) => {
+                let mut service = Self::unsaved();
+                service.report_error(format!("Couldn't find the settings folder: {}", e));
+                service
+            }
+        }
+    }
+
+    /// A service with default settings that can't be saved
+    fn unsaved() -> Self {
+        ConfigService {
+            config: AppConfig::default(),
+            path: None,
+            last_error: None,
+        }
+    }
+
+    /// Loads the configuration from the given file
+    fn load_from(path: PathBuf) -> Self {
+        let mut service = Self::unsaved();
+
+        match load_and_migrate(&path) {
+            Ok((config, version)) => {
+                service.config = config;
+                service.path = Some(path);
+                if version == 0 {
+                    migrate_legacy_sessions(&service.config);
+                }
+                if version < CONFIG_SCHEMA_VERSION {
+                    service.save();
+                }
+            }
+            Err(e) if e.is::<NewerSchemaError>() => {
+                service.report_error(format!("{}; changes won't be saved", e));
+            }
+            Err(e) => {
+                if let Err(copy_error) = fs::copy(&path, path.with_extension("json.bad")) {
+                    eprintln!("Failed to back up unreadable config: {}", copy_error);
+                }
+                service.path = Some(path);
+                service.report_error(format!("Couldn't read settings, using defaults: {}", e));
+            }
+        }
+
+        service
+    }
+
+    /// Returns the current configuration
+    pub fn get(&self) -> &AppConfig {
+        &self.config
+    }
+
+    /// Applies a change to the configuration and saves it
+    ///
+    /// The change is kept in memory even if saving fails; the failure is
+    /// available from `last_error` until the next successful save.
+    pub fn update(&mut self, change: impl FnOnce(&mut AppConfig)) {
+        change(&mut self.config);
+        self.config.ensure_profiles();
+        self.save();
+    }
+
+    /// Applies a change to the active profile and saves the configuration
+    pub fn update_active_profile(&mut self, change: impl FnOnce(&mut Profile)) {
+        self.update(|config| change(config.active_profile_mut()));
+    }
+
+    /// Returns the most recent load or save failure, if any
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Forgets the most recent failure once the user has seen it
+    pub fn dismiss_error(&mut self) {
+        self.last_error = None;
+    }
+
+    /// Writes the configuration, recording any failure
+    fn save(&mut self) {
+        let result = match self.path {
+            Some(ref path) => save_atomically(path, &self.config),
+            None => Err("Settings can't be saved this session".into()),
+        };
+
+        match result {
+            Ok(()) => self.last_error = None,
+            Err(e) => self.report_error(format!("Couldn't save settings: {}", e)),
         }
     }
+
+    /// Logs a failure and keeps it for the UI
+    fn report_error(&mut self, error: String) {
+        eprintln!("{}", error);
+        self.last_error = Some(error);
+    }
 }
 
-/// Attempts to load the configuration, returning an error if it fails
-fn try_load_config() -> Result<AppConfig, Box<dyn std::error::Error>> {
-    let config_path = get_config_path()?;
+/// Moves sessions saved before profiles existed into the default profile,
+/// once the config has been migrated from version 0
+fn migrate_legacy_sessions(config: &AppConfig) {
+    let Some(default_profile) = config.profiles.iter().find(|p| p.id == DEFAULT_PROFILE_ID) else {
+        return;
+    };
+    if let Err(e) = profile::migrate_legacy_sessions(default_profile) {
+        eprintln!("Failed to migrate existing sessions: {}", e);
+    }
+}
+
+/// The config file was written by a newer version of the app
+#[derive(Debug)]
+struct NewerSchemaError(u32);
 
-    if !config_path.exists() {
-        return Ok(AppConfig::default());
+impl std::fmt::Display for NewerSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Settings were saved by a newer version (schema {}, expected {})",
+            self.0, CONFIG_SCHEMA_VERSION
+        )
     }
+}
 
-    let contents = fs::read_to_string(&config_path)?;
-    let config: AppConfig = serde_json::from_str(&contents)?;
+impl std::error::Error for NewerSchemaError {}
 
-    Ok(config)
+/// Gets the full path to the config file
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(crate::paths::config_dir()?.join("config.json"))
+}
+
+/// Reads the config file and runs any pending migrations
+///
+/// A missing file is treated as an empty version 0 config so a fresh install
+/// goes through the same migrations. Returns the config and the version it
+/// was migrated from; anything older than `CONFIG_SCHEMA_VERSION` needs saving.
+fn load_and_migrate(path: &Path) -> Result<(AppConfig, u32), Box<dyn std::error::Error>> {
+    let mut value = if path.exists() {
+        serde_json::from_str(&fs::read_to_string(path)?)?
+    } else {
+        Value::Object(serde_json::Map::new())
+    };
+
+    let version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+    if version > CONFIG_SCHEMA_VERSION {
+        return Err(NewerSchemaError(version).into());
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut value)?;
+    }
+
+    let mut config: AppConfig = serde_json::from_value(value)?;
+    config.schema_version = CONFIG_SCHEMA_VERSION;
+    config.ensure_profiles();
+
+    Ok((config, version))
 }
 
-/// Saves the application configuration
-pub fn save_config(config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
-    let config_path = get_config_path()?;
+/// Writes the config to a temporary file and renames it into place, so a
+/// crash mid-write never leaves a truncated config behind
+fn save_atomically(path: &Path, config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
     let contents = serde_json::to_string_pretty(config)?;
-    fs::write(&config_path, contents)?;
-    Ok(())
+    let temp_path = path.with_extension("json.tmp");
+
+    let result = fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    Ok(result?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letter_cases_format_letters() {
+        assert_eq!(LetterCase::Uppercase.format_char('a'), "A");
+        assert_eq!(LetterCase::Lowercase.format_char('A'), "a");
+        assert_eq!(LetterCase::AsTyped.format_char('A'), "A");
+        assert_eq!(LetterCase::AsTyped.format_char('a'), "a");
+        assert_eq!(LetterCase::Both.format_char('a'), "Aa");
+        assert_eq!(LetterCase::Both.format_char('B'), "Bb");
+    }
+
+    #[test]
+    fn both_cases_leave_other_characters_alone() {
+        for c in [' ', '3', '!'] {
+            assert_eq!(LetterCase::Both.format_char(c), c.to_string());
+            assert_eq!(LetterCase::Uppercase.format_char(c), c.to_string());
+        }
+    }
+
+    #[test]
+    fn letter_cases_format_words() {
+        assert_eq!(LetterCase::Uppercase.format_word("Cat"), "CAT");
+        assert_eq!(LetterCase::Lowercase.format_word("Cat"), "cat");
+        assert_eq!(LetterCase::AsTyped.format_word("Cat"), "Cat");
+        assert_eq!(LetterCase::Both.format_word("Cat"), "CAT cat");
+    }
+
+    /// Writes a config file into a fresh temporary directory
+    fn config_file(contents: &str) -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, contents).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn v0_settings_move_into_a_default_profile() {
+        let v0 = serde_json::json!({
+            "selected_sound": "Chime",
+            "last_selected_grade": "First",
+            "use_uppercase": false,
+            "color_palette": "Neon"
+        });
+        let (_dir, path) = config_file(&v0.to_string());
+
+        let (config, version) = load_and_migrate(&path).unwrap();
+
+        assert_eq!(version, 0);
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.active_profile.as_deref(), Some(DEFAULT_PROFILE_ID));
+        let profile = config.active_profile();
+        assert_eq!(profile.starting_grade, GradeLevel::First);
+        assert_eq!(profile.letter_case, LetterCase::Lowercase);
+        assert_eq!(profile.color_palette, ColorPalette::Neon);
+        assert_eq!(
+            profile.sound_scheme.sound_for(SoundEvent::WordDiscovered),
+            Some("Chime")
+        );
+
+        let saved = serde_json::to_value(&config).unwrap();
+        for key in [
+            "selected_sound",
+            "last_selected_grade",
+            "use_uppercase",
+            "color_palette",
+        ] {
+            assert!(saved.get(key).is_none(), "{} left behind", key);
+        }
+    }
+
+    #[test]
+    fn missing_file_migrates_like_an_empty_v0_config() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let (config, version) = load_and_migrate(&dir.path().join("config.json")).unwrap();
+
+        assert_eq!(version, 0);
+        assert_eq!(config.profiles.len(), 1);
+        assert_eq!(config.active_profile().id, DEFAULT_PROFILE_ID);
+        assert_eq!(config.active_profile().letter_case, LetterCase::Uppercase);
+    }
+
+    #[test]
+    fn v1_uppercase_toggles_become_letter_cases() {
+        let v1 = serde_json::json!({
+            "schema_version": 1,
+            "profiles": [
+                {"id": "anna", "name": "Anna", "avatar_hue": 10.0, "use_uppercase": false},
+                {"id": "ben", "name": "Ben", "avatar_hue": 200.0, "use_uppercase": true},
+                {"id": "cy", "name": "Cy", "avatar_hue": 100.0}
+            ]
+        });
+        let (_dir, path) = config_file(&v1.to_string());
+
+        let (config, version) = load_and_migrate(&path).unwrap();
+
+        assert_eq!(version, 1);
+        let cases: Vec<LetterCase> = config.profiles.iter().map(|p| p.letter_case).collect();
+        assert_eq!(
+            cases,
+            [
+                LetterCase::Lowercase,
+                LetterCase::Uppercase,
+                LetterCase::Uppercase
+            ]
+        );
+        let saved = serde_json::to_value(&config).unwrap();
+        assert!(saved["profiles"][0].get("use_uppercase").is_none());
+    }
+
+    #[test]
+    fn newer_settings_are_never_overwritten() {
+        let newer = serde_json::json!({"schema_version": CONFIG_SCHEMA_VERSION + 1}).to_string();
+        let (dir, path) = config_file(&newer);
+
+        let mut service = ConfigService::load_from(path.clone());
+        service.update(|config| config.kiosk_mode_enabled = true);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), newer);
+        assert!(!dir.path().join("config.json.bad").exists());
+        assert!(service.last_error().is_some());
+    }
+
+    #[test]
+    fn corrupt_settings_are_backed_up_and_replaced() {
+        let (dir, path) = config_file("{ not json");
+
+        let mut service = ConfigService::load_from(path.clone());
+        assert!(service.last_error().is_some());
+        service.update(|config| config.kiosk_mode_enabled = true);
+
+        let backup = dir.path().join("config.json.bad");
+        assert_eq!(fs::read_to_string(backup).unwrap(), "{ not json");
+        let (saved, _) = load_and_migrate(&path).unwrap();
+        assert!(saved.kiosk_mode_enabled);
+        assert!(service.last_error().is_none());
+    }
+
+    #[test]
+    fn v2_profiles_keep_their_own_sounds() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let v2 = serde_json::json!({
+            "schema_version": 2,
+            "active_profile": "anna",
+            "profiles": [
+                {"id": "anna", "name": "Anna", "avatar_hue": 10.0, "selected_sound": "Chime"},
+                {"id": "ben", "name": "Ben", "avatar_hue": 200.0, "selected_sound": "Bell"},
+                {"id": "cy", "name": "Cy", "avatar_hue": 100.0}
+            ]
+        });
+        fs::write(&path, v2.to_string()).unwrap();
+
+        let (config, version) = load_and_migrate(&path).unwrap();
+
+        assert_eq!(version, 2);
+        let sound = |index: usize, event| config.profiles[index].sound_scheme.sound_for(event);
+        assert_eq!(sound(0, SoundEvent::WordDiscovered), Some("Chime"));
+        assert_eq!(sound(0, SoundEvent::WordCorrect), Some("Chime"));
+        assert_eq!(sound(1, SoundEvent::WordDiscovered), Some("Bell"));
+        assert_eq!(sound(1, SoundEvent::WordCorrect), Some("Bell"));
+        assert_eq!(config.profiles[2].sound_scheme, SoundScheme::default());
+        assert_eq!(sound(0, SoundEvent::LevelUp), Some("Tri-Tone"));
+
+        let saved = serde_json::to_value(&config).unwrap();
+        assert!(saved["profiles"][0].get("selected_sound").is_none());
+    }
 }
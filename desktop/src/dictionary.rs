@@ -1,103 +1,890 @@
-use std::collections::HashSet;
+use crate::trie::Trie;
+use iced::futures::channel::oneshot;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Word list installed on most Unix systems
+const SYSTEM_DICTIONARY: &str = "/usr/share/dict/words";
+
+/// Ranked common words compiled into the app; regenerate with
+/// `shared/word_lists/build_common_words.sh`
+const COMMON_WORDS: &str = include_str!("common_words.txt");
+
+/// Version of the bundled common words; bump with the generator's VERSION
+/// whenever the list changes
+const COMMON_WORDS_VERSION: u32 = 1;
+
+/// Segmentation costs, compared across every way of splitting typed text.
+/// Each character left outside a word costs `SKIPPED_CHAR_COST`, so a word
+/// only helps if its cost is lower than that of the characters it covers:
+/// toddler words always do, while common and system words need at least two
+/// letters. Among full splits the cheapest uses toddler words, then common
+/// words, and as few words as possible; any tie goes to the more common words.
+const SKIPPED_CHAR_COST: u32 = 2;
+const TODDLER_WORD_COST: u32 = 1;
+const COMMON_WORD_COST: u32 = 2;
+const SYSTEM_WORD_COST: u32 = 3;
+
+/// Typo costs for "did you mean" suggestions: any single edit (a missing,
+/// extra, wrong or swapped letter) costs `TYPO_COST`, while the mistakes
+/// toddlers make most, doubling a letter or mixing up look- or sound-alike
+/// letters, cost `LIKELY_TYPO_COST`
+const TYPO_COST: u32 = 2;
+const LIKELY_TYPO_COST: u32 = 1;
+
+/// Shortest toddler word offered as a suggestion
+const MIN_SUGGESTION_LENGTH: usize = 3;
+
+/// Letters toddlers mix up because they look or sound alike
+const CONFUSABLE_LETTERS: &[(char, char)] = &[
+    ('b', 'd'),
+    ('p', 'q'),
+    ('m', 'w'),
+    ('n', 'u'),
+    ('c', 'k'),
+    ('s', 'z'),
+    ('i', 'y'),
+];
+
+/// Where a dictionary word comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordSource {
+    /// The curated toddler-friendly list, preferred when splitting words
+    Toddler,
+    /// The bundled common words, with their rank (1 is most common)
+    Common(u32),
+    /// The system dictionary
+    System,
+}
+
+impl WordSource {
+    fn cost(&self) -> u32 {
+        match self {
+            WordSource::Toddler => TODDLER_WORD_COST,
+            WordSource::Common(_) => COMMON_WORD_COST,
+            WordSource::System => SYSTEM_WORD_COST,
+        }
+    }
+
+    /// Returns how uncommon the word is, for breaking ties between splits
+    fn rank(&self) -> u32 {
+        match self {
+            WordSource::Toddler => 0,
+            WordSource::Common(rank) => *rank,
+            // Below every common word
+            WordSource::System => 1000,
+        }
+    }
+}
 
 /// Manages dictionary operations for word validation
+#[derive(Default)]
 pub struct Dictionary {
-    toddler_words: HashSet<String>,
-    system_words: HashSet<String>,
+    words: Trie<WordSource>,
+    /// Toddler words in list order, searched for "did you mean" suggestions
+    toddler_words: Vec<String>,
 }
 
-impl Dictionary {
-    /// Creates a new Dictionary by loading both toddler-friendly and system words
-    pub fn new() -> Self {
-        let mut toddler_words = HashSet::new();
-        let mut system_words = HashSet::new();
-
-        // First, load our curated toddler-friendly words
-        let toddler_word_list = include_str!("toddler_words.txt");
-        for line in toddler_word_list.lines() {
-            let line = line.trim();
-            // Skip comments and empty lines
-            if !line.starts_with('#') && !line.is_empty() {
-                toddler_words.insert(line.to_lowercase());
-            }
+impl std::fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The full word tree is far too large to print
+        f.debug_struct("Dictionary")
+            .field("toddler_words", &self.toddler_words.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Best way found to split the text up to some position
+#[derive(Debug, Clone, Copy)]
+struct Split {
+    cost: u32,
+    words: u32,
+    /// Sum of the words' ranks
+    rank: u32,
+    /// Where the last step started, and whether it was a word or a skipped
+    /// character
+    from: usize,
+    is_word: bool,
+}
+
+/// Parent controls over which words can be discovered
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictionarySettings {
+    /// Also accept words from the system dictionary, on top of the bundled
+    /// toddler and common words
+    #[serde(default = "default_use_system_dictionary")]
+    pub use_system_dictionary: bool,
+    /// Longest word accepted from either dictionary, in letters
+    #[serde(default = "default_max_word_length")]
+    pub max_word_length: usize,
+    /// Rarest rank of the bundled common words accepted, where each rank
+    /// holds about a hundred words; `None` turns the check off. The system
+    /// dictionary has no ranks, so only its words that are also on the
+    /// bundled list are limited
+    #[serde(default)]
+    pub max_word_rank: Option<u32>,
+    /// Words never accepted, on top of the bundled blocklist
+    #[serde(default)]
+    pub blocked_words: BTreeSet<String>,
+    /// Words always accepted, even if blocked by the bundled list or too
+    /// long or rare
+    #[serde(default)]
+    pub allowed_words: BTreeSet<String>,
+}
+
+fn default_use_system_dictionary() -> bool {
+    false
+}
+
+fn default_max_word_length() -> usize {
+    10
+}
+
+impl Default for DictionarySettings {
+    fn default() -> Self {
+        DictionarySettings {
+            use_system_dictionary: default_use_system_dictionary(),
+            max_word_length: default_max_word_length(),
+            max_word_rank: None,
+            blocked_words: BTreeSet::new(),
+            allowed_words: BTreeSet::new(),
         }
+    }
+}
+
+/// Decides which words from the word lists make it into the dictionary
+#[derive(Debug, Clone)]
+pub struct WordFilter {
+    blocked: HashSet<String>,
+    allowed: HashSet<String>,
+    max_length: usize,
+    max_rank: Option<u32>,
+}
 
-        // Also load system dictionary for additional words (all lengths)
-        if let Ok(file) = File::open("/usr/share/dict/words") {
-            let reader = BufReader::new(file);
-            for word in reader.lines().map_while(Result::ok) {
-                let word_lower = word.to_lowercase();
-                // Include all words that contain only letters (any length)
-                if !word_lower.is_empty() && word_lower.chars().all(|c| c.is_alphabetic()) {
-                    system_words.insert(word_lower);
+impl Default for WordFilter {
+    /// A filter that accepts every word
+    fn default() -> Self {
+        WordFilter {
+            blocked: HashSet::new(),
+            allowed: HashSet::new(),
+            max_length: usize::MAX,
+            max_rank: None,
+        }
+    }
+}
+
+impl WordFilter {
+    /// Builds the filter described by the settings, with the bundled
+    /// blocklist
+    pub fn from_settings(settings: &DictionarySettings) -> Self {
+        let mut blocked: HashSet<String> = word_list(include_str!("blocked_words.txt"))
+            .filter(|word| !settings.allowed_words.contains(word))
+            .collect();
+        blocked.extend(settings.blocked_words.iter().map(|w| w.to_lowercase()));
+
+        WordFilter {
+            blocked,
+            allowed: settings
+                .allowed_words
+                .iter()
+                .map(|w| w.to_lowercase())
+                .collect(),
+            max_length: settings.max_word_length,
+            max_rank: settings.max_word_rank,
+        }
+    }
+
+    /// Checks whether a lowercase word from the given list is accepted
+    fn accepts(&self, word: &str, source: WordSource) -> bool {
+        if self.blocked.contains(word) {
+            return false;
+        }
+        if self.allowed.contains(word) {
+            return true;
+        }
+        if word.chars().count() > self.max_length {
+            return false;
+        }
+        // Only the bundled common words are ranked
+        match (self.max_rank, source) {
+            (Some(max_rank), WordSource::Common(rank)) => rank <= max_rank,
+            _ => true,
+        }
+    }
+}
+
+/// Reads the lowercase words from a bundled list, skipping comments and
+/// empty lines
+fn word_list(contents: &str) -> impl Iterator<Item = String> + '_ {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Reads the bundled common words with their ranks, which are set by the
+/// `# rank N` line before each group
+fn common_words(contents: &str) -> impl Iterator<Item = (String, u32)> + '_ {
+    let mut rank = 0;
+    contents.lines().map(str::trim).filter_map(move |line| {
+        if let Some(number) = line.strip_prefix("# rank ") {
+            rank = number.parse().unwrap_or(rank + 1);
+            None
+        } else if line.starts_with('#') || line.is_empty() {
+            None
+        } else {
+            Some((line.to_lowercase(), rank))
+        }
+    })
+}
+
+impl Dictionary {
+    /// Creates a new Dictionary from the toddler-friendly and common words
+    /// and, if enabled, the system words, keeping only the words the settings
+    /// allow
+    ///
+    /// Reading the system dictionary can take a while, so the app builds this
+    /// with `load_in_background`.
+    pub fn load(settings: &DictionarySettings) -> Self {
+        let filter = WordFilter::from_settings(settings);
+        let mut dictionary = Dictionary::default();
+        dictionary.add_toddler_words(settings, &filter);
+
+        let common = common_words(COMMON_WORDS).map(|(w, rank)| (w, WordSource::Common(rank)));
+        dictionary.add_words(common, &filter);
+
+        // The system dictionary differs between machines, so it is opt-in
+        if settings.use_system_dictionary {
+            match File::open(SYSTEM_DICTIONARY) {
+                Ok(file) => {
+                    let system_words = BufReader::new(file).lines().map_while(Result::ok);
+                    dictionary.add_system_words(system_words, &filter);
                 }
+                Err(e) => eprintln!("System dictionary unavailable: {}", e),
             }
         }
 
-        Dictionary {
-            toddler_words,
-            system_words,
-        }
+        dictionary
     }
 
-    /// Checks if a word exists in the dictionary
-    pub fn is_valid_word(&self, word: &str) -> bool {
-        let word_lower = word.to_lowercase();
+    /// Creates a Dictionary of just the toddler words, which is quick enough
+    /// to build while the full dictionary loads
+    pub fn toddler_only(settings: &DictionarySettings) -> Self {
+        let settings = DictionarySettings {
+            use_system_dictionary: false,
+            ..settings.clone()
+        };
+        let filter = WordFilter::from_settings(&settings);
+        let mut dictionary = Dictionary::default();
+        dictionary.add_toddler_words(&settings, &filter);
+        dictionary
+    }
 
-        // Allow all word lengths, including single letters like "a" and "I"
-        if word_lower.is_empty() {
-            return false;
+    /// Builds the full dictionary on a background thread
+    ///
+    /// Resolves to `None` if loading failed.
+    pub async fn load_in_background(settings: DictionarySettings) -> Option<Arc<Dictionary>> {
+        let (sender, receiver) = oneshot::channel();
+        std::thread::spawn(move || {
+            let started = Instant::now();
+            let dictionary = Dictionary::load(&settings);
+            eprintln!(
+                "Dictionary loaded in {:?} (common words version {})",
+                started.elapsed(),
+                COMMON_WORDS_VERSION
+            );
+            let _ = sender.send(Arc::new(dictionary));
+        });
+        receiver.await.ok()
+    }
+
+    /// Adds the toddler words and the parent's allowed words, which count as
+    /// toddler words
+    fn add_toddler_words(&mut self, settings: &DictionarySettings, filter: &WordFilter) {
+        let toddler_words = word_list(include_str!("toddler_words.txt"))
+            .chain(settings.allowed_words.iter().cloned());
+        self.add_words(toddler_words.map(|w| (w, WordSource::Toddler)), filter);
+    }
+
+    /// Adds the system words the filter accepts
+    ///
+    /// Words on the bundled common list are skipped, so one left out for
+    /// being too rare doesn't come back from the system dictionary.
+    fn add_system_words(&mut self, words: impl IntoIterator<Item = String>, filter: &WordFilter) {
+        let ranked: HashSet<String> = common_words(COMMON_WORDS).map(|(w, _)| w).collect();
+        let unranked = words
+            .into_iter()
+            .filter(|w| !ranked.contains(&w.to_lowercase()));
+        self.add_words(unranked.map(|w| (w, WordSource::System)), filter);
+    }
+
+    /// Adds the words the filter accepts
+    ///
+    /// Words are lowercased and anything but letters is skipped. A word
+    /// already in the dictionary keeps its first source, so lists should be
+    /// added from most to least preferred.
+    fn add_words(
+        &mut self,
+        words: impl IntoIterator<Item = (String, WordSource)>,
+        filter: &WordFilter,
+    ) {
+        for (word, source) in words {
+            let word = word.to_lowercase();
+            if word.is_empty()
+                || !word.chars().all(|c| c.is_alphabetic())
+                || !filter.accepts(&word, source)
+                || self.words.get(&word).is_some()
+            {
+                continue;
+            }
+            self.words.entry(&word, source);
+            if source == WordSource::Toddler {
+                self.toddler_words.push(word);
+            }
         }
+    }
 
-        // Check toddler words first (preferred), then system dictionary
-        self.toddler_words.contains(&word_lower) || self.system_words.contains(&word_lower)
+    /// Finds the toddler word that the typed text is most likely a near miss
+    /// of, for "did you mean" hints. Returns `None` if the text is already a
+    /// word or nothing is close enough.
+    /// For example: "dgo" and "bog" both suggest "dog"
+    pub fn suggest(&self, text: &str) -> Option<String> {
+        let typed: Vec<char> = text
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .collect();
+        if typed.len() < 2 {
+            return None;
+        }
+        let typed_word: String = typed.iter().collect();
+        if self.words.get(&typed_word).is_some() {
+            return None;
+        }
+
+        // Short words allow one mistake, longer ones one and a half
+        let max_cost = if typed.len() <= 4 {
+            TYPO_COST
+        } else {
+            TYPO_COST + LIKELY_TYPO_COST
+        };
+
+        // Ties go to the word listed first, as the simpler words come first
+        let mut best: Option<(u32, &String)> = None;
+        for word in &self.toddler_words {
+            let chars: Vec<char> = word.chars().collect();
+            // Very short words are close to almost anything
+            if chars.len() < MIN_SUGGESTION_LENGTH || chars.len().abs_diff(typed.len()) > 2 {
+                continue;
+            }
+            let cost = typo_cost(&typed, &chars);
+            if cost <= max_cost && best.is_none_or(|(best_cost, _)| cost < best_cost) {
+                best = Some((cost, word));
+            }
+        }
+        best.map(|(_, word)| word.clone())
     }
 
-    /// Parses compound words from a string, finding all valid words within it
+    /// Parses compound words from a string, finding the best split of its
+    /// letters into words. Non-letters are ignored, and letters that don't fit
+    /// any word are skipped.
     /// For example: "dogcat" returns ["dog", "cat"]
     pub fn parse_compound_words(&self, text: &str) -> Vec<String> {
-        let mut found_words = Vec::new();
-        let text_lower = text.to_lowercase();
-        let text_chars: Vec<char> = text_lower.chars().filter(|c| c.is_alphabetic()).collect();
+        let chars: Vec<char> = text
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .collect();
+
+        // best[i] is the cheapest split of the first i characters
+        let mut best: Vec<Option<Split>> = vec![None; chars.len() + 1];
+        best[0] = Some(Split {
+            cost: 0,
+            words: 0,
+            rank: 0,
+            from: 0,
+            is_word: false,
+        });
+
+        for start in 0..chars.len() {
+            let Some(here) = best[start] else {
+                continue;
+            };
+            let skip = Split {
+                cost: here.cost + SKIPPED_CHAR_COST,
+                words: here.words,
+                rank: here.rank,
+                from: start,
+                is_word: false,
+            };
+            Self::keep_better(&mut best[start + 1], skip);
 
-        if text_chars.is_empty() {
-            return found_words;
+            for (len, source) in self.words.prefixes(&chars[start..]) {
+                let word = Split {
+                    cost: here.cost + source.cost(),
+                    words: here.words + 1,
+                    rank: here.rank + source.rank(),
+                    from: start,
+                    is_word: true,
+                };
+                Self::keep_better(&mut best[start + len], word);
+            }
         }
 
-        let text_clean: String = text_chars.iter().collect();
-        self.parse_recursive(&text_clean, &mut found_words);
+        // Walk back from the end to collect the chosen words
+        let mut found_words = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let Some(split) = best[end] else {
+                break;
+            };
+            if split.is_word {
+                found_words.push(chars[split.from..end].iter().collect());
+            }
+            end = split.from;
+        }
+        found_words.reverse();
 
         // Return all found words (duplicates are OK)
         found_words
     }
 
-    /// Recursive helper to find all words in a string
-    fn parse_recursive(&self, remaining: &str, found: &mut Vec<String>) {
-        if remaining.is_empty() {
-            return;
+    /// Replaces `slot` with `candidate` if it is cheaper, or equally cheap
+    /// with fewer or more common words
+    fn keep_better(slot: &mut Option<Split>, candidate: Split) {
+        let key = |split: &Split| (split.cost, split.words, split.rank);
+        let better = match slot {
+            None => true,
+            Some(current) => key(&candidate) < key(current),
+        };
+        if better {
+            *slot = Some(candidate);
         }
+    }
+}
 
-        // Try to find the longest valid word starting from the beginning
-        let mut found_word = false;
+/// Checks whether two letters are a common toddler mix-up
+fn confusable(a: char, b: char) -> bool {
+    CONFUSABLE_LETTERS
+        .iter()
+        .any(|&(x, y)| (a, b) == (x, y) || (a, b) == (y, x))
+}
 
-        // Start with longest possible and work down to single letters
-        for end in (1..=remaining.len()).rev() {
-            let candidate = &remaining[0..end];
+/// Weighted edit distance from what was typed to a word, counting swapped
+/// neighbours as one edit and making likely toddler mistakes cheaper
+fn typo_cost(typed: &[char], word: &[char]) -> u32 {
+    // An extra typed letter is likely if it repeats the letter before it,
+    // and a missing one if the word doubles it
+    let extra = |i: usize| {
+        if i > 0 && typed[i] == typed[i - 1] {
+            LIKELY_TYPO_COST
+        } else {
+            TYPO_COST
+        }
+    };
+    let missing = |j: usize| {
+        if j > 0 && word[j] == word[j - 1] {
+            LIKELY_TYPO_COST
+        } else {
+            TYPO_COST
+        }
+    };
 
-            if self.is_valid_word(candidate) {
-                found.push(candidate.to_string());
-                // Recursively parse the rest
-                self.parse_recursive(&remaining[end..], found);
-                found_word = true;
-                break;
+    // cost[i][j] turns the first i typed letters into the first j word letters
+    let mut cost = vec![vec![0; word.len() + 1]; typed.len() + 1];
+    for i in 1..=typed.len() {
+        cost[i][0] = cost[i - 1][0] + extra(i - 1);
+    }
+    for j in 1..=word.len() {
+        cost[0][j] = cost[0][j - 1] + missing(j - 1);
+    }
+
+    for i in 1..=typed.len() {
+        for j in 1..=word.len() {
+            let (a, b) = (typed[i - 1], word[j - 1]);
+            let substitution = if a == b {
+                0
+            } else if confusable(a, b) {
+                LIKELY_TYPO_COST
+            } else {
+                TYPO_COST
+            };
+            let mut best = (cost[i - 1][j - 1] + substitution)
+                .min(cost[i - 1][j] + extra(i - 1))
+                .min(cost[i][j - 1] + missing(j - 1));
+            if i > 1 && j > 1 && a == word[j - 2] && typed[i - 2] == b {
+                best = best.min(cost[i - 2][j - 2] + TYPO_COST);
             }
+            cost[i][j] = best;
         }
+    }
+    cost[typed.len()][word.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary(toddler: &[&str], system: &[&str]) -> Dictionary {
+        filtered(toddler, system, WordFilter::default())
+    }
+
+    fn parse(dictionary: &Dictionary, text: &str) -> Vec<String> {
+        dictionary.parse_compound_words(text)
+    }
+
+    #[test]
+    fn splits_compound_words() {
+        let dict = dictionary(
+            &["dog", "cat", "bird", "the", "park", "in", "to", "into"],
+            &["ran"],
+        );
+        assert_eq!(parse(&dict, "dogcat"), ["dog", "cat"]);
+        assert_eq!(parse(&dict, "dogcatbird"), ["dog", "cat", "bird"]);
+        assert_eq!(
+            parse(&dict, "ranintothepark"),
+            ["ran", "into", "the", "park"]
+        );
+    }
+
+    #[test]
+    fn finds_nothing_without_words() {
+        let dict = dictionary(&["dog", "cat"], &[]);
+        assert!(parse(&dict, "xyz123").is_empty());
+        assert!(parse(&dict, "").is_empty());
+        assert!(parse(&dict, "123 !?").is_empty());
+    }
+
+    #[test]
+    fn does_not_let_a_long_word_swallow_the_next() {
+        // Greedy longest-prefix matching takes "then" and strands "ews"
+        let dict = dictionary(&["the", "then", "news"], &[]);
+        assert_eq!(parse(&dict, "thenews"), ["the", "news"]);
+    }
 
-        // If no word found at this position, try starting from next character
-        if !found_word && remaining.len() > 1 {
-            self.parse_recursive(&remaining[1..], found);
+    #[test]
+    fn prefers_toddler_words_over_system_words() {
+        let dict = dictionary(&["sun", "flower"], &["sunflower"]);
+        assert_eq!(parse(&dict, "sunflower"), ["sun", "flower"]);
+    }
+
+    #[test]
+    fn prefers_fewer_longer_words() {
+        let dict = dictionary(&["sun", "flower", "sunflower", "in", "to", "into"], &[]);
+        assert_eq!(parse(&dict, "sunflower"), ["sunflower"]);
+        assert_eq!(parse(&dict, "into"), ["into"]);
+    }
+
+    #[test]
+    fn uses_system_words_when_needed() {
+        let dict = dictionary(&["dog"], &["zebra"]);
+        assert_eq!(parse(&dict, "zebradog"), ["zebra", "dog"]);
+    }
+
+    #[test]
+    fn ignores_single_letter_system_words() {
+        let dict = dictionary(&["a"], &["x", "b"]);
+        assert!(parse(&dict, "xb").is_empty());
+        assert_eq!(parse(&dict, "xa"), ["a"]);
+    }
+
+    #[test]
+    fn skips_letters_between_words() {
+        let dict = dictionary(&["dog", "cat"], &[]);
+        assert_eq!(parse(&dict, "qqdogxcatzz"), ["dog", "cat"]);
+    }
+
+    #[test]
+    fn ignores_case_and_punctuation() {
+        let dict = dictionary(&["dog", "cat"], &[]);
+        assert_eq!(parse(&dict, "Dog-CAT!"), ["dog", "cat"]);
+    }
+
+    #[test]
+    fn handles_multibyte_input() {
+        let dict = dictionary(&["dog", "café"], &[]);
+        assert_eq!(parse(&dict, "日本dog語"), ["dog"]);
+        assert_eq!(parse(&dict, "CAFÉdog"), ["café", "dog"]);
+    }
+
+    #[test]
+    fn bundled_toddler_list_loads() {
+        let settings = DictionarySettings {
+            use_system_dictionary: false,
+            ..Default::default()
+        };
+        let dict = Dictionary::load(&settings);
+        assert_eq!(parse(&dict, "dogcat"), ["dog", "cat"]);
+        assert_eq!(parse(&dict, "intothepark"), ["into", "the", "park"]);
+        assert_eq!(dict.suggest("dgo").as_deref(), Some("dog"));
+        assert_eq!(dict.suggest("catt").as_deref(), Some("cat"));
+    }
+
+    fn filtered(toddler: &[&str], system: &[&str], filter: WordFilter) -> Dictionary {
+        let mut dictionary = Dictionary::default();
+        let toddler = toddler.iter().map(|w| (w.to_string(), WordSource::Toddler));
+        let system = system.iter().map(|w| (w.to_string(), WordSource::System));
+        dictionary.add_words(toddler.chain(system), &filter);
+        dictionary
+    }
+
+    fn set(words: &[&str]) -> HashSet<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn blocked_words_are_dropped_from_both_lists() {
+        let filter = WordFilter {
+            blocked: set(&["cat", "zebra"]),
+            ..Default::default()
+        };
+        let dict = filtered(&["cat", "dog"], &["zebra"], filter);
+        assert_eq!(parse(&dict, "catdogzebra"), ["dog"]);
+    }
+
+    #[test]
+    fn bundled_blocklist_applies_unless_allowed() {
+        let dict = filtered(
+            &[],
+            &["damn", "dog"],
+            WordFilter::from_settings(&Default::default()),
+        );
+        assert_eq!(parse(&dict, "damndog"), ["dog"]);
+
+        let settings = DictionarySettings {
+            allowed_words: ["damn".to_string()].into(),
+            ..Default::default()
+        };
+        let dict = filtered(&[], &["damn"], WordFilter::from_settings(&settings));
+        assert_eq!(parse(&dict, "damn"), ["damn"]);
+    }
+
+    #[test]
+    fn custom_block_beats_allow() {
+        let settings = DictionarySettings {
+            blocked_words: ["dog".to_string()].into(),
+            allowed_words: ["dog".to_string()].into(),
+            ..Default::default()
+        };
+        let dict = filtered(&["dog"], &[], WordFilter::from_settings(&settings));
+        assert!(parse(&dict, "dog").is_empty());
+    }
+
+    #[test]
+    fn long_words_are_dropped_unless_allowed() {
+        let filter = WordFilter {
+            max_length: 5,
+            allowed: set(&["elephant"]),
+            ..Default::default()
+        };
+        let dict = filtered(&["dinosaur", "elephant"], &["hippopotamus"], filter);
+        assert!(parse(&dict, "dinosaur").is_empty());
+        assert!(parse(&dict, "hippopotamus").is_empty());
+        assert_eq!(parse(&dict, "elephant"), ["elephant"]);
+    }
+
+    #[test]
+    fn rare_words_are_dropped() {
+        let settings = DictionarySettings {
+            use_system_dictionary: true,
+            max_word_rank: Some(3),
+            allowed_words: ["sunflower".to_string()].into(),
+            ..Default::default()
+        };
+        let mut dict = Dictionary::default();
+        dict.add_words(
+            [
+                ("yak".to_string(), WordSource::Toddler),
+                ("was".to_string(), WordSource::Common(1)),
+                ("air".to_string(), WordSource::Common(8)),
+                ("sunflower".to_string(), WordSource::Common(5)),
+                ("zebra".to_string(), WordSource::System),
+            ],
+            &WordFilter::from_settings(&settings),
+        );
+        assert_eq!(parse(&dict, "was"), ["was"]);
+        assert!(parse(&dict, "air").is_empty());
+        // System words have no rank to limit
+        assert_eq!(parse(&dict, "zebra"), ["zebra"]);
+        // Toddler and allowed words are never too rare
+        assert_eq!(parse(&dict, "yak"), ["yak"]);
+        assert_eq!(parse(&dict, "sunflower"), ["sunflower"]);
+    }
+
+    #[test]
+    fn bundled_ranks_limit_common_words() {
+        let everything = Dictionary::load(&DictionarySettings::default());
+        let top_hundred = Dictionary::load(&DictionarySettings {
+            max_word_rank: Some(1),
+            ..Default::default()
+        });
+        // "about" is among the hundred most common words and "doctor" is not
+        assert_eq!(parse(&top_hundred, "about"), ["about"]);
+        assert_eq!(parse(&everything, "doctor"), ["doctor"]);
+        // Shorter common words like "do" may still be found inside it
+        assert!(!parse(&top_hundred, "doctor").contains(&"doctor".to_string()));
+    }
+
+    #[test]
+    fn system_words_keep_their_bundled_rank() {
+        let settings = DictionarySettings {
+            max_word_rank: Some(1),
+            ..Default::default()
+        };
+        let mut dict = Dictionary::load(&settings);
+
+        dict.add_system_words(
+            ["Doctor".to_string(), "quokka".to_string()],
+            &WordFilter::from_settings(&settings),
+        );
+
+        // "doctor" is ranked below the top hundred; "quokka" isn't ranked at all
+        assert!(!parse(&dict, "doctor").contains(&"doctor".to_string()));
+        assert_eq!(parse(&dict, "quokka"), ["quokka"]);
+    }
+
+    #[test]
+    fn bundled_toddler_words_are_not_blocked() {
+        let blocked: HashSet<String> = word_list(include_str!("blocked_words.txt")).collect();
+        for word in word_list(include_str!("toddler_words.txt")) {
+            assert!(!blocked.contains(&word), "{} is blocked", word);
         }
     }
+
+    #[test]
+    fn suggests_words_for_near_misses() {
+        let dict = dictionary(&["dog", "cat", "ball", "duck", "apple"], &[]);
+        // Swapped letters
+        assert_eq!(dict.suggest("dgo").as_deref(), Some("dog"));
+        // Doubled and missing double letters
+        assert_eq!(dict.suggest("catt").as_deref(), Some("cat"));
+        assert_eq!(dict.suggest("bal").as_deref(), Some("ball"));
+        // Look- and sound-alike letters
+        assert_eq!(dict.suggest("bog").as_deref(), Some("dog"));
+        assert_eq!(dict.suggest("kat").as_deref(), Some("cat"));
+        assert_eq!(dict.suggest("buck").as_deref(), Some("duck"));
+        // One wrong letter in a longer word
+        assert_eq!(dict.suggest("appke").as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn suggests_nothing_for_words_or_mashing() {
+        let dict = dictionary(&["dog", "cat"], &["cot"]);
+        assert_eq!(dict.suggest("dog"), None);
+        assert_eq!(dict.suggest("cot"), None);
+        assert_eq!(dict.suggest("xqzv"), None);
+        assert_eq!(dict.suggest("d"), None);
+        assert_eq!(dict.suggest(""), None);
+    }
+
+    #[test]
+    fn suggests_only_toddler_words() {
+        let dict = dictionary(&["dog"], &["zebra"]);
+        assert_eq!(dict.suggest("zebar"), None);
+        assert_eq!(dict.suggest("dgo").as_deref(), Some("dog"));
+    }
+
+    #[test]
+    fn suggests_no_blocked_words() {
+        let filter = WordFilter {
+            blocked: set(&["dog"]),
+            ..Default::default()
+        };
+        let dict = filtered(&["dog"], &[], filter);
+        assert_eq!(dict.suggest("dgo"), None);
+    }
+
+    #[test]
+    fn likely_typos_cost_less() {
+        let chars = |text: &str| text.chars().collect::<Vec<_>>();
+        assert_eq!(typo_cost(&chars("dog"), &chars("dog")), 0);
+        assert_eq!(typo_cost(&chars("dgo"), &chars("dog")), TYPO_COST);
+        assert_eq!(typo_cost(&chars("bog"), &chars("dog")), LIKELY_TYPO_COST);
+        assert_eq!(typo_cost(&chars("doog"), &chars("dog")), LIKELY_TYPO_COST);
+        assert_eq!(typo_cost(&chars("dg"), &chars("dog")), TYPO_COST);
+    }
+
+    #[test]
+    fn common_words_are_ranked() {
+        let words: Vec<(String, u32)> = common_words(COMMON_WORDS).collect();
+        assert!(words.len() > 1000);
+        assert_eq!(words[0], ("a".to_string(), 1));
+        assert!(words.iter().all(|(_, rank)| (1..=11).contains(rank)));
+        assert!(words.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(words
+            .iter()
+            .all(|(word, _)| word.chars().all(|c| c.is_ascii_lowercase())));
+    }
+
+    #[test]
+    fn common_words_version_matches_list() {
+        let header = format!("# version {}", COMMON_WORDS_VERSION);
+        assert!(COMMON_WORDS.lines().any(|line| line == header));
+    }
+
+    #[test]
+    fn system_dictionary_is_opt_in() {
+        assert!(!DictionarySettings::default().use_system_dictionary);
+    }
+
+    #[test]
+    fn bundled_dictionary_finds_common_words() {
+        let dict = Dictionary::load(&DictionarySettings::default());
+        // "because" and "family" are toddler words; "answer" and "whole"
+        // only come from the common words
+        assert_eq!(parse(&dict, "becausefamily"), ["because", "family"]);
+        assert_eq!(parse(&dict, "answerwhole"), ["answer", "whole"]);
+    }
+
+    #[test]
+    fn prefers_toddler_then_common_then_system_words() {
+        let mut dict = Dictionary::default();
+        let words = [
+            ("sun".to_string(), WordSource::Toddler),
+            ("sunflower".to_string(), WordSource::Common(5)),
+            ("flower".to_string(), WordSource::System),
+            ("sunflowers".to_string(), WordSource::System),
+        ];
+        dict.add_words(words, &WordFilter::default());
+        assert_eq!(parse(&dict, "sunflower"), ["sunflower"]);
+        assert_eq!(parse(&dict, "sunflowers"), ["sunflowers"]);
+    }
+
+    #[test]
+    fn ties_go_to_more_common_words() {
+        let mut dict = Dictionary::default();
+        let words = [
+            ("catch".to_string(), WordSource::Common(9)),
+            ("air".to_string(), WordSource::Common(8)),
+            ("cat".to_string(), WordSource::Common(1)),
+            ("chair".to_string(), WordSource::Common(2)),
+        ];
+        dict.add_words(words, &WordFilter::default());
+        assert_eq!(parse(&dict, "catchair"), ["cat", "chair"]);
+    }
+
+    #[test]
+    fn toddler_only_skips_common_words() {
+        let settings = DictionarySettings {
+            allowed_words: ["zebra".to_string()].into(),
+            ..Default::default()
+        };
+        let dict = Dictionary::toddler_only(&settings);
+        assert_eq!(parse(&dict, "dogcat"), ["dog", "cat"]);
+        assert_eq!(parse(&dict, "zebra"), ["zebra"]);
+        assert!(!parse(&dict, "answer").contains(&"answer".to_string()));
+    }
+
+    #[test]
+    fn loads_in_background() {
+        let settings = DictionarySettings::default();
+        let dict = iced::futures::executor::block_on(Dictionary::load_in_background(settings))
+            .expect("dictionary loads");
+        assert_eq!(parse(&dict, "answerwhole"), ["answer", "whole"]);
+    }
 }
@@ -1,15 +1,85 @@
 use iced::Color;
 
+/// Seconds a discovered word takes to fly up into the discovered-words strip
+const FLY_UP_SECS: f32 = 0.7;
+
 /// Represents a word that was successfully typed by the toddler
 #[derive(Debug, Clone)]
 pub struct DiscoveredWord {
     pub text: String,
     pub color: Color,
+    /// Seconds since the word was found
+    age: f32,
 }
 
 impl DiscoveredWord {
     /// Creates a new discovered word with the given text and color
     pub fn new(text: String, color: Color) -> Self {
-        DiscoveredWord { text, color }
+        DiscoveredWord {
+            text,
+            color,
+            age: 0.0,
+        }
+    }
+
+    /// Advances the fly-up animation by `dt` seconds
+    pub fn update(&mut self, dt: f32) {
+        self.age += dt;
+    }
+
+    /// Checks whether the word is still on its way to the strip
+    pub fn is_flying(&self) -> bool {
+        self.age < FLY_UP_SECS
+    }
+
+    /// Returns how far along its flight the word is, from 0.0 to 1.0, easing
+    /// out as it arrives
+    pub fn flight_progress(&self) -> f32 {
+        let t = (self.age / FLY_UP_SECS).min(1.0);
+        1.0 - (1.0 - t).powi(3)
+    }
+}
+
+/// A "did you mean" hint shown after a typed segment that had no words in it
+#[derive(Debug, Clone)]
+pub struct WordSuggestion {
+    pub word: String,
+    /// Index of the last typed letter of the segment
+    pub after_letter: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{Animator, ManualClock};
+    use std::time::Duration;
+
+    #[test]
+    fn words_fly_up_and_land() {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        animator.frame();
+        let mut word = DiscoveredWord::new("cat".to_string(), Color::WHITE);
+        assert_eq!(word.flight_progress(), 0.0);
+        assert!(word.is_flying());
+
+        let mut last = 0.0;
+        for _ in 0..50 {
+            clock.advance(Duration::from_millis(16));
+            word.update(animator.frame());
+            assert!(word.flight_progress() >= last);
+            last = word.flight_progress();
+        }
+
+        assert_eq!(word.flight_progress(), 1.0);
+        assert!(!word.is_flying());
+    }
+
+    #[test]
+    fn flight_eases_out() {
+        let mut word = DiscoveredWord::new("cat".to_string(), Color::WHITE);
+        word.update(FLY_UP_SECS / 2.0);
+        assert!(word.flight_progress() > 0.5);
+        assert!(word.is_flying());
     }
 }
@@ -14,7 +14,6 @@ pub enum GradeLevel {
 }
 
 impl GradeLevel {
-    #[allow(dead_code)]
     pub fn all() -> Vec<GradeLevel> {
         vec![
             GradeLevel::PreK,
@@ -41,7 +40,6 @@ impl GradeLevel {
         }
     }
 
-    #[allow(dead_code)]
     pub fn short_name(&self) -> &str {
         match self {
             GradeLevel::PreK => "Pre-K",
@@ -1,15 +1,126 @@
 use iced::Color;
 
+/// Seconds a new letter takes to pop in
+const POP_IN_SECS: f32 = 0.3;
+
+/// Seconds a letter stays highlighted after being part of a discovered word
+const HIGHLIGHT_SECS: f32 = 0.8;
+
 /// Represents a single letter with its associated color
 #[derive(Debug, Clone)]
 pub struct Letter {
     pub character: char,
     pub color: Color,
+    /// Seconds since the letter was typed
+    age: f32,
+    /// Seconds of highlight left
+    highlight: f32,
 }
 
 impl Letter {
     /// Creates a new Letter with the given character and color
     pub fn new(character: char, color: Color) -> Self {
-        Letter { character, color }
+        Letter {
+            character,
+            color,
+            age: 0.0,
+            highlight: 0.0,
+        }
+    }
+
+    /// Advances the letter's animations by `dt` seconds
+    pub fn update(&mut self, dt: f32) {
+        self.age += dt;
+        self.highlight = (self.highlight - dt).max(0.0);
+    }
+
+    /// Checks whether the letter is still popping in or highlighted
+    pub fn is_animating(&self) -> bool {
+        self.age < POP_IN_SECS || self.highlight > 0.0
+    }
+
+    /// Lights the letter up because it is part of a word that was just found
+    pub fn highlight(&mut self) {
+        self.highlight = HIGHLIGHT_SECS;
+    }
+
+    /// Returns the size multiplier while popping in, overshooting slightly
+    /// before settling at 1.0
+    pub fn scale(&self) -> f32 {
+        let t = (self.age / POP_IN_SECS).min(1.0);
+        // Ease-out with overshoot ("back" easing)
+        let overshoot = 1.70158;
+        let x = t - 1.0;
+        1.0 + (overshoot + 1.0) * x.powi(3) + overshoot * x.powi(2)
+    }
+
+    /// Returns the color to draw, brightened while highlighted
+    pub fn display_color(&self) -> Color {
+        let amount = self.highlight / HIGHLIGHT_SECS;
+        // Pulse twice as the highlight fades
+        let glow = amount * (0.5 + 0.5 * (amount * std::f32::consts::TAU * 2.0).cos()) * 0.7;
+        Color::from_rgb(
+            self.color.r + (1.0 - self.color.r) * glow,
+            self.color.g + (1.0 - self.color.g) * glow,
+            self.color.b + (1.0 - self.color.b) * glow,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{Animator, ManualClock};
+    use std::time::Duration;
+
+    /// Steps a letter through `frames` frames of 16ms each
+    fn run(letter: &mut Letter, clock: &ManualClock, animator: &mut Animator, frames: usize) {
+        for _ in 0..frames {
+            clock.advance(Duration::from_millis(16));
+            letter.update(animator.frame());
+        }
+    }
+
+    fn animator() -> (ManualClock, Animator) {
+        let clock = ManualClock::new();
+        let mut animator = Animator::new(Box::new(clock.clone()));
+        animator.frame();
+        (clock, animator)
+    }
+
+    #[test]
+    fn letters_pop_in_from_nothing() {
+        let (clock, mut animator) = animator();
+        let mut letter = Letter::new('a', Color::from_rgb(0.2, 0.4, 0.6));
+        assert!(letter.scale().abs() < 1e-6);
+        assert!(letter.is_animating());
+
+        let mut peak: f32 = 0.0;
+        for _ in 0..30 {
+            run(&mut letter, &clock, &mut animator, 1);
+            peak = peak.max(letter.scale());
+        }
+
+        assert!(peak > 1.0, "pop-in never overshot: {}", peak);
+        assert!((letter.scale() - 1.0).abs() < 1e-6);
+        assert!(!letter.is_animating());
+    }
+
+    #[test]
+    fn highlight_brightens_then_fades() {
+        let (clock, mut animator) = animator();
+        let color = Color::from_rgb(0.2, 0.4, 0.6);
+        let mut letter = Letter::new('a', color);
+        run(&mut letter, &clock, &mut animator, 30);
+        assert_eq!(letter.display_color(), color);
+
+        letter.highlight();
+        let lit = letter.display_color();
+        assert!(lit.r > color.r && lit.g > color.g && lit.b > color.b);
+        assert!(letter.is_animating());
+
+        run(&mut letter, &clock, &mut animator, 60);
+        assert_eq!(letter.display_color(), color);
+        assert!(!letter.is_animating());
     }
 }
@@ -1,3 +1,4 @@
+mod animation;
 mod app;
 mod audio;
 mod celebration;
@@ -8,11 +9,23 @@ mod grade_level;
 mod kiosk_mode;
 mod letter;
 mod message;
+mod music;
+mod paths;
+mod phonics;
+mod profile;
+mod progress;
+mod recorder;
 mod session;
+mod session_store;
+mod sound_pack;
+mod sound_scheme;
+mod spaced_repetition;
 mod speech;
 mod system_sound;
 mod tic_tac_toe;
+mod trie;
 mod utils;
+mod voice_recording;
 mod word_challenge;
 mod word_list_loader;
 
@@ -32,6 +45,11 @@ fn main() -> iced::Result {
     if let Ok(cwd) = env::current_dir() {
         eprintln!("Words with Toddlers started from: {:?}", cwd);
     }
+
+    // Resolve config/data locations before anything reads or writes them
+    paths::set_overrides(paths::PathOverrides::from_args(env::args().skip(1)));
+    paths::migrate_legacy_locations();
+
     iced::application(
         "Words with Toddlers",
         WordsWithToddlers::update,
@@ -1,6 +1,12 @@
-use crate::config::ColorPalette;
+use crate::celebration::CelebrationStyle;
+use crate::config::{ColorPalette, LetterCase, PhonicsMode};
+use crate::dictionary::Dictionary;
+use crate::grade_level::GradeLevel;
 use crate::kiosk_mode::KioskModeStatus;
+use crate::progress::ProgressReport;
+use crate::sound_scheme::SoundEvent;
 use iced::keyboard;
+use std::sync::Arc;
 
 /// Application messages for handling user interactions
 #[derive(Debug, Clone)]
@@ -15,6 +21,10 @@ pub enum Message {
     ToggleCursor,
     /// Navigate to settings screen
     NavigateToSettings,
+    /// Navigate to the parent progress dashboard
+    NavigateToProgress,
+    /// Navigate to the parent voice recording screen
+    NavigateToRecording,
     /// Navigate to welcome screen
     NavigateToWelcome,
     /// Navigate to main (discovery mode) screen
@@ -26,14 +36,28 @@ pub enum Message {
     StartAudioChallenge,
     /// Replay the current word (audio mode)
     ReplayWord,
-    /// Select a sound by name
-    SelectSound(String),
-    /// Toggle uppercase/lowercase display
-    ToggleUppercase(bool),
+    /// Choose which event the Settings sound picker edits
+    SelectSoundEvent(SoundEvent),
+    /// Set the sound for the event being edited; `None` silences it
+    SelectSound(Option<String>),
+    /// Select the background music track; `None` turns music off
+    SelectMusicTrack(Option<String>),
+    /// Set the background music volume
+    SelectMusicVolume(f32),
+    /// The window gained (true) or lost (false) focus
+    WindowFocusChanged(bool),
+    /// Select how letters and words are displayed
+    SelectLetterCase(LetterCase),
+    /// Select whether typed letters are sounded out in Discovery mode
+    SelectPhonicsMode(PhonicsMode),
+    /// Select the animation played when a challenge word is correct
+    SelectCelebrationStyle(CelebrationStyle),
     /// Check if typed word is correct (challenge mode)
     CheckTypedWord,
     /// Finish celebration and load next word
     FinishCelebration,
+    /// Advance running animations by one frame
+    AnimationFrame,
     /// Exit challenge mode
     ExitChallenge,
     /// Show tic-tac-toe mode selection
@@ -58,4 +82,46 @@ pub enum Message {
     RequestAccessibilityPermission,
     /// Select a color palette for letter colors
     SelectColorPalette(ColorPalette),
+    /// Switch to the profile with the given id
+    SelectProfile(String),
+    /// The new-profile name field was edited
+    NewProfileNameChanged(String),
+    /// Create a profile from the new-profile name field
+    AddProfile,
+    /// Delete the profile with the given id
+    RemoveProfile(String),
+    /// Set the starting grade level for the active profile
+    SelectStartingGrade(GradeLevel),
+    /// Turn words from the system dictionary on or off
+    SelectSystemDictionary(bool),
+    /// Only accept words up to this rank of the bundled common words
+    SelectMaxWordRank(Option<u32>),
+    /// The custom word field was edited
+    CustomWordChanged(String),
+    /// Never accept the word in the custom word field
+    BlockCustomWord,
+    /// Always accept the word in the custom word field
+    AllowCustomWord,
+    /// Take a word off the custom block and allow lists
+    RemoveCustomWord(String),
+    /// The full dictionary finished loading in the background, for the
+    /// reload with the given number
+    DictionaryLoaded(u64, Option<Arc<Dictionary>>),
+    /// The progress report for the profile with the given id finished
+    /// loading in the background
+    ProgressLoaded(String, Option<ProgressReport>),
+    /// Hide the settings error banner
+    DismissConfigError,
+    /// Choose which grade's words to record
+    SelectRecordingGrade(GradeLevel),
+    /// Start or stop recording the current word
+    ToggleRecording,
+    /// Play back the current word's recorded clip
+    PlayRecordedClip,
+    /// Move to the next word to record
+    NextRecordingWord,
+    /// Move to the previous word to record
+    PreviousRecordingWord,
+    /// Leave the recording screen
+    ExitRecording,
 }
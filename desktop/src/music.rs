@@ -0,0 +1,221 @@
+use crate::audio::{self, Sound, SYNTH_SAMPLE_RATE};
+use std::f32::consts::TAU;
+use std::fs;
+use std::path::PathBuf;
+
+/// Music volumes offered in Settings
+pub const VOLUME_PRESETS: [(&str, f32); 3] = [("Quiet", 0.25), ("Medium", 0.5), ("Loud", 0.8)];
+
+/// A background music option in Settings
+#[derive(Debug, Clone)]
+pub struct MusicTrack {
+    /// Stored in the config; built-ins use their name, user tracks `user/<file>`
+    pub id: String,
+    pub display_name: String,
+    pub sound: Sound,
+}
+
+/// Every background track: the built-in tracks followed by the user's own
+/// from `<data>/music`
+#[derive(Debug, Clone)]
+pub struct MusicLibrary {
+    tracks: Vec<MusicTrack>,
+}
+
+impl MusicLibrary {
+    /// Collects the built-in tracks and any music files the user added
+    pub fn load() -> Self {
+        let mut tracks = vec![
+            MusicTrack {
+                id: "Twinkle".to_string(),
+                display_name: "Twinkle".to_string(),
+                sound: Sound::Synthesized {
+                    name: "Twinkle",
+                    render: render_twinkle,
+                },
+            },
+            MusicTrack {
+                id: "Drift".to_string(),
+                display_name: "Drift".to_string(),
+                sound: Sound::Synthesized {
+                    name: "Drift",
+                    render: render_drift,
+                },
+            },
+        ];
+        tracks.extend(user_tracks());
+        MusicLibrary { tracks }
+    }
+
+    /// Returns every available track, in picker order
+    pub fn all(&self) -> &[MusicTrack] {
+        &self.tracks
+    }
+
+    /// Gets the track with the given id
+    pub fn get(&self, id: &str) -> Option<&MusicTrack> {
+        self.tracks.iter().find(|track| track.id == id)
+    }
+}
+
+/// Lists the playable files in the user's music directory, by file name
+fn user_tracks() -> Vec<MusicTrack> {
+    let Ok(dir) = music_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && audio::is_supported_file(path))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let file_name = path.file_name()?.to_str()?.to_string();
+            let display_name = path.file_stem()?.to_str()?.to_string();
+            Some(MusicTrack {
+                id: format!("user/{}", file_name),
+                display_name,
+                sound: Sound::File(path),
+            })
+        })
+        .collect()
+}
+
+/// Gets the directory holding the user's own music
+fn music_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(crate::paths::data_dir()?.join("music"))
+}
+
+/// A note as (MIDI note number, length in beats); note 0 is a rest
+type Note = (u8, f32);
+
+/// "Twinkle, Twinkle, Little Star" on a music box, over a soft bass
+fn render_twinkle() -> Vec<i16> {
+    const C: u8 = 72;
+    const D: u8 = 74;
+    const E: u8 = 76;
+    const F: u8 = 77;
+    const G: u8 = 79;
+    const A: u8 = 81;
+    let opening = [
+        (C, 1.0),
+        (C, 1.0),
+        (G, 1.0),
+        (G, 1.0),
+        (A, 1.0),
+        (A, 1.0),
+        (G, 2.0),
+        (F, 1.0),
+        (F, 1.0),
+        (E, 1.0),
+        (E, 1.0),
+        (D, 1.0),
+        (D, 1.0),
+        (C, 2.0),
+    ];
+    let middle = [
+        (G, 1.0),
+        (G, 1.0),
+        (F, 1.0),
+        (F, 1.0),
+        (E, 1.0),
+        (E, 1.0),
+        (D, 2.0),
+    ];
+    let melody: Vec<Note> = [&opening[..], &middle, &middle, &opening].concat();
+
+    // One bass note per bar of four beats
+    let bass: Vec<Note> = [48, 53, 48, 43, 48, 43, 48, 43, 48, 53, 48, 43]
+        .iter()
+        .map(|&note| (note, 4.0))
+        .collect();
+
+    let mut track = Track::new(melody_beats(&melody), 84.0);
+    track.add_voice(&melody, 0.22, 0.9);
+    track.add_voice(&bass, 0.12, 2.5);
+    track.finish()
+}
+
+/// Slow arpeggios over C, A minor, F and G
+fn render_drift() -> Vec<i16> {
+    let chords: [[u8; 3]; 4] = [[60, 64, 67], [57, 60, 64], [53, 57, 60], [55, 59, 62]];
+    let mut arpeggios = Vec::new();
+    let mut bass = Vec::new();
+    for [root, third, fifth] in chords {
+        for note in [root, third, fifth, root + 12, fifth, third] {
+            arpeggios.push((note + 12, 0.5));
+        }
+        bass.push((root - 12, 3.0));
+    }
+    // Play the progression twice so the loop isn't too short
+    let arpeggios = [arpeggios.clone(), arpeggios].concat();
+    let bass = [bass.clone(), bass].concat();
+
+    let mut track = Track::new(melody_beats(&arpeggios), 66.0);
+    track.add_voice(&arpeggios, 0.16, 1.2);
+    track.add_voice(&bass, 0.1, 3.0);
+    track.finish()
+}
+
+/// Total length of a sequence of notes, in beats
+fn melody_beats(notes: &[Note]) -> f32 {
+    notes.iter().map(|(_, beats)| beats).sum()
+}
+
+/// Mixes plucked voices into a loop of a fixed length
+///
+/// Each note rings out past its written length; anything that runs off the
+/// end wraps around to the start, so the loop repeats without a seam.
+struct Track {
+    samples: Vec<f32>,
+    samples_per_beat: f32,
+}
+
+impl Track {
+    fn new(beats: f32, tempo_bpm: f32) -> Self {
+        let samples_per_beat = SYNTH_SAMPLE_RATE as f32 * 60.0 / tempo_bpm;
+        Track {
+            samples: vec![0.0; (beats * samples_per_beat) as usize],
+            samples_per_beat,
+        }
+    }
+
+    /// Adds a sequence of notes, each a sine with a soft octave overtone that
+    /// fades out over `decay_secs`
+    fn add_voice(&mut self, notes: &[Note], amplitude: f32, decay_secs: f32) {
+        let rate = SYNTH_SAMPLE_RATE as f32;
+        let ring_samples = (decay_secs * 3.0 * rate) as usize;
+        let attack_samples = 0.01 * rate;
+        let mut start = 0.0;
+
+        for &(note, beats) in notes {
+            if note != 0 {
+                let frequency = 440.0 * 2f32.powf((note as f32 - 69.0) / 12.0);
+                let first = start as usize;
+                for i in 0..ring_samples {
+                    let t = i as f32 / rate;
+                    let envelope = (i as f32 / attack_samples).min(1.0) * (-t / decay_secs).exp();
+                    let phase = TAU * frequency * t;
+                    let value = phase.sin() + 0.25 * (2.0 * phase).sin();
+                    let index = (first + i) % self.samples.len();
+                    self.samples[index] += amplitude * envelope * value;
+                }
+            }
+            start += beats * self.samples_per_beat;
+        }
+    }
+
+    fn finish(self) -> Vec<i16> {
+        self.samples
+            .into_iter()
+            .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .collect()
+    }
+}
@@ -0,0 +1,284 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Name of the per-application directory inside the platform locations
+const APP_DIR_NAME: &str = "WordsWithToddlers";
+
+/// Environment variable that overrides the config directory
+pub const CONFIG_DIR_ENV: &str = "WORDS_WITH_TODDLERS_CONFIG_DIR";
+
+/// Environment variable that overrides the data directory
+pub const DATA_DIR_ENV: &str = "WORDS_WITH_TODDLERS_DATA_DIR";
+
+/// Directory overrides given on the command line
+#[derive(Debug, Clone, Default)]
+pub struct PathOverrides {
+    pub config_dir: Option<PathBuf>,
+    pub data_dir: Option<PathBuf>,
+}
+
+impl PathOverrides {
+    /// Parses `--config-dir <path>` and `--data-dir <path>` (or `--flag=path`)
+    /// from the command-line arguments, ignoring anything else
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut overrides = PathOverrides::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+
+            let target = match flag.as_str() {
+                "--config-dir" => &mut overrides.config_dir,
+                "--data-dir" => &mut overrides.data_dir,
+                _ => continue,
+            };
+
+            match inline_value.or_else(|| args.next()) {
+                Some(value) => *target = Some(PathBuf::from(value)),
+                None => eprintln!("Missing path after {}", flag),
+            }
+        }
+
+        overrides
+    }
+
+    /// Fills in directories not given on the command line from the
+    /// environment variables
+    fn with_env(self) -> Self {
+        PathOverrides {
+            config_dir: self
+                .config_dir
+                .or_else(|| std::env::var_os(CONFIG_DIR_ENV).map(PathBuf::from)),
+            data_dir: self
+                .data_dir
+                .or_else(|| std::env::var_os(DATA_DIR_ENV).map(PathBuf::from)),
+        }
+    }
+
+    /// Checks whether either directory has been overridden
+    fn is_set(&self) -> bool {
+        self.config_dir.is_some() || self.data_dir.is_some()
+    }
+}
+
+static OVERRIDES: OnceLock<PathOverrides> = OnceLock::new();
+
+/// Installs the command-line overrides; must be called before any path is resolved
+pub fn set_overrides(overrides: PathOverrides) {
+    if OVERRIDES.set(overrides).is_err() {
+        eprintln!("Path overrides were already set");
+    }
+}
+
+/// Picks the command-line override, then the environment variable, then the default
+fn resolve(
+    cli_override: Option<&PathBuf>,
+    env_var: &str,
+    default: Option<PathBuf>,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = cli_override
+        .cloned()
+        .or_else(|| std::env::var_os(env_var).map(PathBuf::from))
+        .or_else(|| default.map(|base| base.join(APP_DIR_NAME)))
+        .ok_or("Could not determine a directory for application files")?;
+
+    // Create directory if it doesn't exist
+    fs::create_dir_all(&dir)?;
+
+    Ok(dir)
+}
+
+/// Gets the configuration directory
+///
+/// `~/.config` (XDG) on Linux, `~/Library/Application Support` on macOS and
+/// `%APPDATA%` on Windows, unless overridden.
+pub fn config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let overrides = OVERRIDES.get();
+    resolve(
+        overrides.and_then(|o| o.config_dir.as_ref()),
+        CONFIG_DIR_ENV,
+        dirs::config_dir(),
+    )
+}
+
+/// Gets the data directory holding profiles and session history
+///
+/// `~/.local/share` (XDG) on Linux, `~/Library/Application Support` on macOS
+/// and `%APPDATA%` on Windows, unless overridden.
+pub fn data_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let overrides = OVERRIDES.get();
+    resolve(
+        overrides.and_then(|o| o.data_dir.as_ref()),
+        DATA_DIR_ENV,
+        dirs::data_dir(),
+    )
+}
+
+/// Moves files left behind by older versions into the current locations
+///
+/// Older builds always wrote the config to `~/Library/Application Support`
+/// (even on Linux) and sessions to `~/Documents/WordsWithToddlers`. Entries
+/// that already exist at the destination are left where they are. Nothing is
+/// moved when either directory is overridden, so a test run or portable
+/// install never takes over the user's real files.
+pub fn migrate_legacy_locations() {
+    let overrides = OVERRIDES.get().cloned().unwrap_or_default().with_env();
+    migrate_legacy_locations_from(&overrides, dirs::home_dir(), dirs::document_dir());
+}
+
+/// Migrates from the legacy directories under the given home and documents
+/// directories
+fn migrate_legacy_locations_from(
+    overrides: &PathOverrides,
+    home_dir: Option<PathBuf>,
+    documents_dir: Option<PathBuf>,
+) {
+    if overrides.is_set() {
+        eprintln!("Directories are overridden; leaving files from older versions in place");
+        return;
+    }
+
+    if let Some(home_dir) = home_dir {
+        let legacy_config_dir = home_dir
+            .join("Library")
+            .join("Application Support")
+            .join(APP_DIR_NAME);
+        if let Err(e) = config_dir().and_then(|dir| migrate_dir(&legacy_config_dir, &dir)) {
+            eprintln!("Failed to migrate old config directory: {}", e);
+        }
+    }
+
+    if let Some(documents_dir) = documents_dir {
+        let legacy_data_dir = documents_dir.join(APP_DIR_NAME);
+        if let Err(e) = data_dir().and_then(|dir| migrate_dir(&legacy_data_dir, &dir)) {
+            eprintln!("Failed to migrate old data directory: {}", e);
+        }
+    }
+}
+
+/// Moves each entry of `from` into `to` unless `to` already has one by that name
+fn migrate_dir(from: &Path, to: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    if !from.is_dir() || from == to {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if target.exists() {
+            continue;
+        }
+        move_path(&entry.path(), &target)?;
+        eprintln!("Moved {:?} to {:?}", entry.path(), target);
+    }
+
+    Ok(())
+}
+
+/// Renames a file or directory, copying instead when it crosses filesystems
+fn move_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    if from.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            move_path(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        fs::remove_dir(from)
+    } else {
+        fs::copy(from, to)?;
+        fs::remove_file(from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates `<base>/<relative>/profiles.json` and returns its path
+    fn legacy_file(base: &Path, relative: &[&str]) -> PathBuf {
+        let dir = relative
+            .iter()
+            .fold(base.to_path_buf(), |dir, part| dir.join(part));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("profiles.json");
+        fs::write(&file, "{}").unwrap();
+        file
+    }
+
+    #[test]
+    fn parses_flags_with_and_without_equals() {
+        let args = [
+            "--config-dir",
+            "/tmp/config",
+            "--verbose",
+            "--data-dir=/tmp/data",
+        ];
+        let overrides = PathOverrides::from_args(args.iter().map(|a| a.to_string()));
+        assert_eq!(overrides.config_dir, Some(PathBuf::from("/tmp/config")));
+        assert_eq!(overrides.data_dir, Some(PathBuf::from("/tmp/data")));
+    }
+
+    #[test]
+    fn migrate_dir_keeps_existing_entries() {
+        let from = tempfile::tempdir().unwrap();
+        let to = tempfile::tempdir().unwrap();
+        fs::write(from.path().join("config.json"), "old").unwrap();
+        fs::write(from.path().join("profiles.json"), "moved").unwrap();
+        fs::write(to.path().join("config.json"), "new").unwrap();
+
+        migrate_dir(from.path(), to.path()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(to.path().join("config.json")).unwrap(),
+            "new"
+        );
+        assert_eq!(
+            fs::read_to_string(to.path().join("profiles.json")).unwrap(),
+            "moved"
+        );
+        assert!(from.path().join("config.json").exists());
+        assert!(!from.path().join("profiles.json").exists());
+    }
+
+    #[test]
+    fn overridden_directories_skip_migration() {
+        let home = tempfile::tempdir().unwrap();
+        let documents = tempfile::tempdir().unwrap();
+        let config_override = tempfile::tempdir().unwrap();
+        let data_override = tempfile::tempdir().unwrap();
+        let legacy_config = legacy_file(
+            home.path(),
+            &["Library", "Application Support", APP_DIR_NAME],
+        );
+        let legacy_data = legacy_file(documents.path(), &[APP_DIR_NAME]);
+
+        let only_data = PathOverrides {
+            config_dir: None,
+            data_dir: Some(data_override.path().to_path_buf()),
+        };
+        let only_config = PathOverrides {
+            config_dir: Some(config_override.path().to_path_buf()),
+            data_dir: None,
+        };
+        for overrides in [only_data, only_config] {
+            migrate_legacy_locations_from(
+                &overrides,
+                Some(home.path().to_path_buf()),
+                Some(documents.path().to_path_buf()),
+            );
+        }
+
+        assert!(legacy_config.exists());
+        assert!(legacy_data.exists());
+        assert_eq!(fs::read_dir(config_override.path()).unwrap().count(), 0);
+        assert_eq!(fs::read_dir(data_override.path()).unwrap().count(), 0);
+    }
+}
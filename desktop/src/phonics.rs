@@ -0,0 +1,107 @@
+use crate::audio::Sound;
+use crate::config::PhonicsMode;
+use crate::speech::Utterance;
+
+/// Embeds a clip for each letter from `shared/sounds/phonics/<dir>`
+macro_rules! letter_clips {
+    ($dir:literal) => {
+        letter_clips!($dir; "a" "b" "c" "d" "e" "f" "g" "h" "i" "j" "k" "l" "m" "n"
+            "o" "p" "q" "r" "s" "t" "u" "v" "w" "x" "y" "z")
+    };
+    ($dir:literal; $($letter:literal)*) => {
+        [$(Sound::Embedded {
+            name: concat!("phonics/", $dir, "/", $letter),
+            bytes: include_bytes!(concat!(
+                "../../shared/sounds/phonics/", $dir, "/", $letter, ".wav"
+            )),
+        }),*]
+    };
+}
+
+/// Each letter's name ("bee"), rendered by `shared/sounds/phonics/generate.py`
+const LETTER_NAMES: [Sound; 26] = letter_clips!("names");
+
+/// Each letter's most common sound ("buh"), rendered by
+/// `shared/sounds/phonics/generate.py`
+const LETTER_SOUNDS: [Sound; 26] = letter_clips!("sounds");
+
+/// Returns what to say for a typed letter, or `None` if phonics is off or
+/// the character isn't an English letter
+pub fn letter_utterance(c: char, mode: PhonicsMode) -> Option<Utterance> {
+    if !c.is_ascii_alphabetic() {
+        return None;
+    }
+    let index = (c.to_ascii_lowercase() as u8 - b'a') as usize;
+
+    let clips = match mode {
+        PhonicsMode::Off => return None,
+        PhonicsMode::LetterNames => &LETTER_NAMES,
+        PhonicsMode::LetterSounds => &LETTER_SOUNDS,
+    };
+    Some(Utterance::Clip(clips[index].clone()))
+}
+
+/// Sounds a word out letter by letter, then says the whole word
+pub fn blend_word(word: &str, mode: PhonicsMode) -> Vec<Utterance> {
+    if mode == PhonicsMode::Off {
+        return Vec::new();
+    }
+
+    word.chars()
+        .filter_map(|c| letter_utterance(c, mode))
+        .chain(std::iter::once(Utterance::Text(word.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::{Decoder, Source};
+    use std::io::Cursor;
+
+    #[test]
+    fn every_letter_has_a_playable_clip() {
+        for mode in [PhonicsMode::LetterNames, PhonicsMode::LetterSounds] {
+            for c in 'a'..='z' {
+                let Some(Utterance::Clip(Sound::Embedded { name, bytes })) =
+                    letter_utterance(c, mode)
+                else {
+                    panic!("no clip for {:?} in {:?}", c, mode);
+                };
+                let decoder = Decoder::new(Cursor::new(bytes)).expect(name);
+                assert!(
+                    decoder
+                        .total_duration()
+                        .is_some_and(|d| d.as_millis() > 200),
+                    "{}",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn uppercase_letters_share_clips() {
+        let clip_name = |c| match letter_utterance(c, PhonicsMode::LetterSounds) {
+            Some(Utterance::Clip(Sound::Embedded { name, .. })) => name,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(clip_name('B'), clip_name('b'));
+        assert_eq!(clip_name('b'), "phonics/sounds/b");
+    }
+
+    #[test]
+    fn other_characters_are_silent() {
+        assert!(letter_utterance('3', PhonicsMode::LetterNames).is_none());
+        assert!(letter_utterance('é', PhonicsMode::LetterNames).is_none());
+        assert!(letter_utterance('a', PhonicsMode::Off).is_none());
+        assert!(blend_word("cat", PhonicsMode::Off).is_empty());
+    }
+
+    #[test]
+    fn blending_ends_with_the_whole_word() {
+        let blended = blend_word("cat", PhonicsMode::LetterSounds);
+        assert_eq!(blended.len(), 4);
+        assert!(matches!(&blended[3], Utterance::Text(word) if word == "cat"));
+    }
+}
@@ -0,0 +1,284 @@
+use crate::celebration::CelebrationStyle;
+use crate::config::{ColorPalette, LegacySettings, LetterCase, PhonicsMode};
+use crate::grade_level::GradeLevel;
+use crate::sound_scheme::SoundScheme;
+use crate::spaced_repetition::ReviewSchedule;
+use crate::word_challenge::AdaptiveState;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Id given to the profile created from a pre-profiles configuration
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// Hues handed out to new profiles in turn, so siblings get distinct avatars
+const AVATAR_HUES: &[f32] = &[210.0, 330.0, 120.0, 40.0, 270.0, 180.0, 0.0, 60.0];
+
+/// A child's profile with their own settings and history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub avatar_hue: f32,
+    #[serde(default)]
+    pub starting_grade: GradeLevel,
+    #[serde(default)]
+    pub color_palette: ColorPalette,
+    #[serde(default)]
+    pub letter_case: LetterCase,
+    #[serde(default)]
+    pub phonics: PhonicsMode,
+    #[serde(default)]
+    pub celebration_style: CelebrationStyle,
+    #[serde(default)]
+    pub sound_scheme: SoundScheme,
+}
+
+impl Profile {
+    /// Creates a new profile, copying its display settings from `template`
+    pub fn new(name: &str, existing: &[Profile], template: &Profile) -> Self {
+        Profile {
+            id: unique_id(name, existing),
+            name: name.trim().to_string(),
+            avatar_hue: AVATAR_HUES[existing.len() % AVATAR_HUES.len()],
+            starting_grade: GradeLevel::default(),
+            color_palette: template.color_palette.clone(),
+            letter_case: template.letter_case,
+            phonics: template.phonics,
+            celebration_style: template.celebration_style,
+            sound_scheme: template.sound_scheme.clone(),
+        }
+    }
+
+    /// Creates the default profile from settings saved before profiles existed
+    pub fn from_legacy_settings(settings: &LegacySettings) -> Self {
+        Profile {
+            id: DEFAULT_PROFILE_ID.to_string(),
+            name: "Player 1".to_string(),
+            avatar_hue: AVATAR_HUES[0],
+            starting_grade: settings.last_selected_grade,
+            color_palette: settings.color_palette.clone(),
+            letter_case: settings.letter_case(),
+            phonics: PhonicsMode::Off,
+            celebration_style: CelebrationStyle::default(),
+            sound_scheme: SoundScheme::default(),
+        }
+    }
+
+    /// Returns the avatar color as RGB components
+    pub fn avatar_color(&self) -> (f32, f32, f32) {
+        crate::utils::color::hsl_to_rgb(self.avatar_hue, 0.7, 0.6)
+    }
+
+    /// Gets this profile's data directory, creating it if it doesn't exist
+    pub fn data_dir(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let dir = profiles_root()?.join(&self.id);
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    /// Gets this profile's sessions directory, creating it if it doesn't exist
+    pub fn sessions_dir(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let dir = self.data_dir()?.join("sessions");
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    /// Loads the adaptive challenge state saved for this profile, if there is one
+    pub fn load_adaptive_state(&self) -> Option<AdaptiveState> {
+        self.load_data("challenge_state.json")
+    }
+
+    /// Saves the adaptive challenge state so the next session resumes from it
+    pub fn save_adaptive_state(
+        &self,
+        state: &AdaptiveState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_data("challenge_state.json", state)
+    }
+
+    /// Loads this profile's spaced-repetition schedule, or an empty one
+    pub fn load_review_schedule(&self) -> ReviewSchedule {
+        self.load_data("review_schedule.json").unwrap_or_default()
+    }
+
+    /// Saves this profile's spaced-repetition schedule
+    pub fn save_review_schedule(
+        &self,
+        schedule: &ReviewSchedule,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_data("review_schedule.json", schedule)
+    }
+
+    /// Reads a JSON file from the profile's data directory, if it exists and parses
+    fn load_data<T: DeserializeOwned>(&self, file_name: &str) -> Option<T> {
+        let path = self.data_dir().ok()?.join(file_name);
+        if !path.exists() {
+            return None;
+        }
+
+        let result = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()));
+        match result {
+            Ok(data) => Some(data),
+            Err(e) => {
+                eprintln!("Failed to load {} (starting fresh): {}", file_name, e);
+                None
+            }
+        }
+    }
+
+    /// Writes a value as JSON into the profile's data directory
+    fn save_data<T: Serialize>(
+        &self,
+        file_name: &str,
+        data: &T,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.data_dir()?.join(file_name);
+        let contents = serde_json::to_string_pretty(data)?;
+        fs::write(&path, contents)?;
+        Ok(())
+    }
+}
+
+/// Gets the directory holding every profile's data
+fn profiles_root() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(crate::paths::data_dir()?.join("profiles"))
+}
+
+/// Builds a filesystem-safe id from the name that doesn't clash with existing profiles
+fn unique_id(name: &str, existing: &[Profile]) -> String {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug.trim_matches('-');
+    let base = if slug.is_empty() { "profile" } else { slug };
+
+    let mut id = base.to_string();
+    let mut suffix = 2;
+    while existing.iter().any(|p| p.id == id) {
+        id = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    id
+}
+
+/// Moves sessions saved before profiles existed into the default profile
+///
+/// Sessions used to live directly in `<data>/sessions`; they now belong to
+/// `<data>/profiles/default/sessions`.
+pub fn migrate_legacy_sessions(
+    default_profile: &Profile,
+) -> Result<(), Box<dyn std::error::Error>> {
+    migrate_legacy_sessions_in(&crate::paths::data_dir()?, default_profile)
+}
+
+/// Moves `<data_dir>/sessions` into the default profile's directory
+fn migrate_legacy_sessions_in(
+    data_dir: &Path,
+    default_profile: &Profile,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let legacy_dir = data_dir.join("sessions");
+    if !legacy_dir.is_dir() {
+        return Ok(());
+    }
+
+    let profile_dir = data_dir.join("profiles").join(&default_profile.id);
+    let target_dir = profile_dir.join("sessions");
+    if target_dir.exists() {
+        // Already migrated (or the profile has its own history) - leave both alone
+        return Ok(());
+    }
+
+    fs::create_dir_all(&profile_dir)?;
+    fs::rename(&legacy_dir, &target_dir)?;
+    eprintln!("Moved existing sessions to {:?}", target_dir);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_profile() -> Profile {
+        Profile::from_legacy_settings(&LegacySettings::default())
+    }
+
+    /// Writes a session file under `<sessions>/<day>/`
+    fn write_session(sessions: &Path, day: &str, contents: &str) {
+        fs::create_dir_all(sessions.join(day)).unwrap();
+        fs::write(sessions.join(day).join("session_09-30-00.json"), contents).unwrap();
+    }
+
+    #[test]
+    fn legacy_sessions_move_into_the_default_profile() {
+        let data = tempfile::tempdir().unwrap();
+        write_session(&data.path().join("sessions"), "2024-05-01", "old");
+
+        migrate_legacy_sessions_in(data.path(), &default_profile()).unwrap();
+
+        let moved = data
+            .path()
+            .join("profiles/default/sessions/2024-05-01/session_09-30-00.json");
+        assert_eq!(fs::read_to_string(moved).unwrap(), "old");
+        assert!(!data.path().join("sessions").exists());
+    }
+
+    #[test]
+    fn existing_profile_sessions_are_left_alone() {
+        let data = tempfile::tempdir().unwrap();
+        write_session(&data.path().join("sessions"), "2024-05-01", "old");
+        let target = data.path().join("profiles/default/sessions");
+        write_session(&target, "2024-06-01", "new");
+
+        migrate_legacy_sessions_in(data.path(), &default_profile()).unwrap();
+
+        assert!(data.path().join("sessions/2024-05-01").is_dir());
+        assert!(!target.join("2024-05-01").exists());
+        let kept = target.join("2024-06-01/session_09-30-00.json");
+        assert_eq!(fs::read_to_string(kept).unwrap(), "new");
+    }
+
+    #[test]
+    fn nothing_to_migrate_without_legacy_sessions() {
+        let data = tempfile::tempdir().unwrap();
+
+        migrate_legacy_sessions_in(data.path(), &default_profile()).unwrap();
+
+        assert!(!data.path().join("profiles").exists());
+    }
+
+    #[test]
+    fn legacy_settings_become_the_default_profile() {
+        let legacy: LegacySettings = serde_json::from_value(serde_json::json!({
+            "last_selected_grade": "Second",
+            "use_uppercase": false,
+            "color_palette": "Pastels"
+        }))
+        .unwrap();
+
+        let profile = Profile::from_legacy_settings(&legacy);
+
+        assert_eq!(profile.id, DEFAULT_PROFILE_ID);
+        assert_eq!(profile.starting_grade, GradeLevel::Second);
+        assert_eq!(profile.letter_case, LetterCase::Lowercase);
+        assert_eq!(profile.color_palette, ColorPalette::Pastels);
+        assert_eq!(profile.phonics, PhonicsMode::Off);
+    }
+
+    #[test]
+    fn new_profiles_get_unique_ids() {
+        let first = default_profile();
+        let anna = Profile::new("Anna", std::slice::from_ref(&first), &first);
+        let anna_again = Profile::new(" anna ", &[first.clone(), anna.clone()], &first);
+
+        assert_eq!(anna.id, "anna");
+        assert_eq!(anna_again.id, "anna-2");
+        assert_eq!(anna_again.name, "anna");
+    }
+}
@@ -0,0 +1,252 @@
+use crate::grade_level::GradeLevel;
+use crate::profile::Profile;
+use crate::session::GameMode;
+use crate::session_store::{SessionFilter, SessionStore, SessionSummary, StoredSession};
+use chrono::{NaiveDate, NaiveDateTime};
+use iced::futures::channel::oneshot;
+use std::collections::BTreeMap;
+
+/// Parent-facing snapshot of a child's progress, built from saved sessions
+#[derive(Debug, Clone, Default)]
+pub struct ProgressReport {
+    pub summary: SessionSummary,
+    /// Number of mastered words at each grade level, in grade order
+    pub mastered_by_grade: Vec<(GradeLevel, usize)>,
+    /// Challenge accuracy for each day that had attempts, oldest first
+    pub daily_accuracy: Vec<(NaiveDate, f32)>,
+    /// Words with the most incorrect attempts and their miss counts
+    pub most_missed: Vec<(String, usize)>,
+    /// Every point where the adaptive challenge changed level, oldest first
+    pub level_history: Vec<(NaiveDateTime, GradeLevel)>,
+}
+
+impl ProgressReport {
+    /// Loads all of a profile's saved sessions and builds a report from them
+    pub fn load(profile: &Profile) -> Self {
+        match SessionStore::for_profile(profile) {
+            Ok(store) => Self::from_sessions(&store.list(&SessionFilter::new())),
+            Err(e) => {
+                eprintln!("Failed to open session history: {}", e);
+                ProgressReport::default()
+            }
+        }
+    }
+
+    /// Loads the report on a background thread, as reading every session
+    /// file can take a while
+    ///
+    /// Resolves to `None` if loading failed.
+    pub async fn load_in_background(profile: Profile) -> Option<ProgressReport> {
+        let (sender, receiver) = oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(Self::load(&profile));
+        });
+        receiver.await.ok()
+    }
+
+    /// Builds a report from already-loaded sessions (expected oldest first)
+    pub fn from_sessions(sessions: &[StoredSession]) -> Self {
+        let summary = SessionSummary::from_sessions(sessions);
+
+        let mastered_by_grade = GradeLevel::all()
+            .into_iter()
+            .map(|grade| {
+                let count = summary
+                    .word_stats
+                    .values()
+                    .filter(|stats| stats.grade_level == Some(grade) && stats.is_mastered())
+                    .count();
+                (grade, count)
+            })
+            .collect();
+
+        let most_missed = summary
+            .most_missed(10)
+            .into_iter()
+            .map(|(word, stats)| (word.to_string(), stats.incorrect))
+            .collect();
+
+        ProgressReport {
+            mastered_by_grade,
+            daily_accuracy: Self::daily_accuracy(sessions),
+            most_missed,
+            level_history: Self::level_history(sessions),
+            summary,
+        }
+    }
+
+    /// Groups challenge attempts by day and computes the accuracy of each
+    fn daily_accuracy(sessions: &[StoredSession]) -> Vec<(NaiveDate, f32)> {
+        let mut by_day: BTreeMap<NaiveDate, (usize, usize)> = BTreeMap::new();
+
+        for stored in sessions {
+            for attempt in &stored.session.attempts {
+                let (correct, total) = by_day.entry(stored.date()).or_default();
+                *total += 1;
+                if attempt.correct {
+                    *correct += 1;
+                }
+            }
+        }
+
+        by_day
+            .into_iter()
+            .map(|(day, (correct, total))| (day, correct as f32 / total as f32))
+            .collect()
+    }
+
+    /// Walks challenge sessions in order and records each change of grade level
+    fn level_history(sessions: &[StoredSession]) -> Vec<(NaiveDateTime, GradeLevel)> {
+        let mut history: Vec<(NaiveDateTime, GradeLevel)> = Vec::new();
+
+        let mut push_level = |at: NaiveDateTime, grade: GradeLevel| {
+            if history.last().map(|(_, last)| *last) != Some(grade) {
+                history.push((at, grade));
+            }
+        };
+
+        for stored in sessions {
+            if stored.session.game_mode != Some(GameMode::Challenge) {
+                continue;
+            }
+            let mut last_at = stored.started_at;
+            for attempt in &stored.session.attempts {
+                last_at = NaiveDateTime::parse_from_str(&attempt.timestamp, "%Y-%m-%d %H:%M:%S")
+                    .unwrap_or(last_at);
+                push_level(last_at, attempt.grade_level);
+            }
+            // The level reached when the session ended may differ from the last attempt
+            if let Some(grade) = stored.session.grade_level {
+                push_level(last_at, grade);
+            }
+        }
+
+        history
+    }
+
+    /// Formats the total practice time as e.g. "1h 05m" or "12m"
+    pub fn practice_time_label(&self) -> String {
+        let minutes = self.summary.total_duration_seconds / 60;
+        if minutes >= 60 {
+            format!("{}h {:02}m", minutes / 60, minutes % 60)
+        } else {
+            format!("{}m", minutes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::Session;
+    use crate::word_challenge::{ChallengeMode, WordAttempt};
+
+    fn at(timestamp: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn attempt(word: &str, correct: bool, grade: GradeLevel, timestamp: &str) -> WordAttempt {
+        WordAttempt {
+            target_word: word.to_string(),
+            typed_text: if correct {
+                word.to_string()
+            } else {
+                String::new()
+            },
+            correct,
+            wrong_attempts: 0,
+            revealed: false,
+            mode: ChallengeMode::Visual,
+            grade_level: grade,
+            timestamp: timestamp.to_string(),
+            time_to_answer_ms: 1000,
+        }
+    }
+
+    fn challenge(
+        started: &str,
+        end_grade: GradeLevel,
+        attempts: Vec<WordAttempt>,
+    ) -> StoredSession {
+        let mut session = Session::new_challenge(end_grade, 0, attempts);
+        session.timestamp = started.to_string();
+        session.duration_seconds = Some(600);
+        StoredSession {
+            started_at: at(started),
+            session,
+        }
+    }
+
+    fn history() -> Vec<StoredSession> {
+        use GradeLevel::{First, Kindergarten};
+        vec![
+            challenge(
+                "2025-03-01 09:00:00",
+                First,
+                vec![
+                    attempt("cat", true, Kindergarten, "2025-03-01 09:01:00"),
+                    attempt("cat", true, Kindergarten, "2025-03-01 09:02:00"),
+                    attempt("dog", false, Kindergarten, "2025-03-01 09:03:00"),
+                    attempt("dog", true, Kindergarten, "2025-03-01 09:04:00"),
+                ],
+            ),
+            challenge(
+                "2025-03-02 09:00:00",
+                First,
+                vec![
+                    attempt("said", false, First, "2025-03-02 09:01:00"),
+                    attempt("said", false, First, "2025-03-02 09:02:00"),
+                    attempt("dog", false, First, "2025-03-02 09:03:00"),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn accuracy_is_grouped_by_day() {
+        let report = ProgressReport::from_sessions(&history());
+
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        assert_eq!(report.daily_accuracy, [(day(1), 0.75), (day(2), 0.0)]);
+    }
+
+    #[test]
+    fn mastery_and_misses_are_counted_per_word() {
+        let report = ProgressReport::from_sessions(&history());
+
+        let mastered: Vec<_> = report
+            .mastered_by_grade
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        assert_eq!(mastered, [&(GradeLevel::Kindergarten, 1)]);
+        assert_eq!(
+            report.most_missed,
+            [("dog".to_string(), 2), ("said".to_string(), 2)]
+        );
+        assert_eq!(report.practice_time_label(), "20m");
+    }
+
+    #[test]
+    fn level_history_records_each_change() {
+        let report = ProgressReport::from_sessions(&history());
+
+        assert_eq!(
+            report.level_history,
+            [
+                (at("2025-03-01 09:01:00"), GradeLevel::Kindergarten),
+                (at("2025-03-01 09:04:00"), GradeLevel::First),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_sessions_make_an_empty_report() {
+        let report = ProgressReport::from_sessions(&[]);
+
+        assert!(report.daily_accuracy.is_empty());
+        assert!(report.most_missed.is_empty());
+        assert!(report.level_history.is_empty());
+        assert_eq!(report.practice_time_label(), "0m");
+    }
+}
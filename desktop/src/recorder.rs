@@ -0,0 +1,239 @@
+use rodio::cpal::{
+    self,
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    FromSample, SizedSample,
+};
+use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// Mono audio captured from the microphone
+#[derive(Debug, Clone)]
+pub struct Recording {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+impl Recording {
+    /// Length of the recording in seconds
+    pub fn duration_secs(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate.max(1) as f32
+    }
+
+    /// Returns a copy with leading and trailing silence removed, keeping a
+    /// little padding so the word doesn't start abruptly
+    pub fn trimmed(&self) -> Recording {
+        const THRESHOLD: f32 = 0.02;
+        let padding = (self.sample_rate / 20) as usize;
+
+        let start = self.samples.iter().position(|s| s.abs() > THRESHOLD);
+        let end = self.samples.iter().rposition(|s| s.abs() > THRESHOLD);
+        let samples = match (start, end) {
+            (Some(start), Some(end)) => {
+                let start = start.saturating_sub(padding);
+                let end = (end + padding + 1).min(self.samples.len());
+                self.samples[start..end].to_vec()
+            }
+            _ => Vec::new(),
+        };
+
+        Recording {
+            samples,
+            sample_rate: self.sample_rate,
+        }
+    }
+
+    /// Writes the recording as a 16-bit mono WAV file
+    pub fn write_wav(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: self.sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut writer = hound::WavWriter::create(path, spec)?;
+        for &sample in &self.samples {
+            writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+        }
+        writer.finalize()?;
+        Ok(())
+    }
+}
+
+/// Records from the default input device until stopped
+///
+/// The input stream runs on its own thread because cpal streams can't be
+/// moved between threads.
+pub struct Recorder {
+    stop: mpsc::Sender<()>,
+    samples: Arc<Mutex<Vec<f32>>>,
+    sample_rate: u32,
+    thread: JoinHandle<()>,
+}
+
+impl Recorder {
+    /// Starts recording from the default microphone
+    pub fn start() -> Result<Self, Box<dyn std::error::Error>> {
+        let samples = Arc::new(Mutex::new(Vec::new()));
+        let (stop, stop_receiver) = mpsc::channel::<()>();
+        let (ready, ready_receiver) = mpsc::channel::<Result<u32, String>>();
+
+        let thread_samples = samples.clone();
+        let thread = std::thread::spawn(move || {
+            let stream = match open_input_stream(thread_samples) {
+                Ok((stream, sample_rate)) => {
+                    let _ = ready.send(Ok(sample_rate));
+                    stream
+                }
+                Err(e) => {
+                    let _ = ready.send(Err(e.to_string()));
+                    return;
+                }
+            };
+
+            // Keep the stream alive until asked to stop (or the recorder is dropped)
+            let _ = stop_receiver.recv();
+            drop(stream);
+        });
+
+        let sample_rate = ready_receiver
+            .recv()
+            .map_err(|_| "Recording thread exited unexpectedly")??;
+
+        Ok(Recorder {
+            stop,
+            samples,
+            sample_rate,
+            thread,
+        })
+    }
+
+    /// Stops recording and returns what was captured
+    pub fn stop(self) -> Recording {
+        let _ = self.stop.send(());
+        let _ = self.thread.join();
+
+        let samples = self
+            .samples
+            .lock()
+            .map(|mut s| std::mem::take(&mut *s))
+            .unwrap_or_default();
+        Recording {
+            samples,
+            sample_rate: self.sample_rate,
+        }
+    }
+}
+
+/// Opens and starts the default input stream, returning it with its sample rate
+fn open_input_stream(
+    samples: Arc<Mutex<Vec<f32>>>,
+) -> Result<(cpal::Stream, u32), Box<dyn std::error::Error>> {
+    let device = cpal::default_host()
+        .default_input_device()
+        .ok_or("No microphone found")?;
+    let supported = device.default_input_config()?;
+    let sample_rate = supported.sample_rate().0;
+    let config = supported.config();
+
+    let stream = match supported.sample_format() {
+        cpal::SampleFormat::F32 => build_stream::<f32>(&device, &config, samples)?,
+        cpal::SampleFormat::I16 => build_stream::<i16>(&device, &config, samples)?,
+        cpal::SampleFormat::U16 => build_stream::<u16>(&device, &config, samples)?,
+        other => return Err(format!("Unsupported microphone sample format {:?}", other).into()),
+    };
+    stream.play()?;
+
+    Ok((stream, sample_rate))
+}
+
+/// Builds an input stream that mixes every frame down to mono f32 samples
+fn build_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    samples: Arc<Mutex<Vec<f32>>>,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = config.channels.max(1) as usize;
+    device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            if let Ok(mut samples) = samples.lock() {
+                for frame in data.chunks(channels) {
+                    let sum: f32 = frame.iter().map(|&s| s.to_sample::<f32>()).sum();
+                    samples.push(sum / frame.len() as f32);
+                }
+            }
+        },
+        |e| eprintln!("Microphone error: {}", e),
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A second of silence at 100 samples per second with a loud stretch in
+    /// the middle
+    fn recording_with_sound(from: usize, to: usize) -> Recording {
+        let mut samples = vec![0.01; 100];
+        samples[from..to].fill(0.5);
+        Recording {
+            samples,
+            sample_rate: 100,
+        }
+    }
+
+    #[test]
+    fn trimming_keeps_the_sound_and_some_padding() {
+        // Padding is a twentieth of a second: 5 samples either side
+        let trimmed = recording_with_sound(40, 60).trimmed();
+
+        assert_eq!(trimmed.samples.len(), 30);
+        assert_eq!(trimmed.samples[..5], [0.01; 5]);
+        assert_eq!(trimmed.samples[5], 0.5);
+        assert_eq!(trimmed.samples[24], 0.5);
+        assert_eq!(trimmed.sample_rate, 100);
+    }
+
+    #[test]
+    fn padding_stops_at_the_ends() {
+        let trimmed = recording_with_sound(2, 98).trimmed();
+        assert_eq!(trimmed.samples.len(), 100);
+    }
+
+    #[test]
+    fn silence_trims_to_nothing() {
+        let silent = Recording {
+            samples: vec![0.01; 100],
+            sample_rate: 100,
+        };
+        let trimmed = silent.trimmed();
+
+        assert!(trimmed.samples.is_empty());
+        assert_eq!(trimmed.duration_secs(), 0.0);
+    }
+
+    #[test]
+    fn written_wavs_are_16_bit_mono() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clip.wav");
+        let recording = Recording {
+            samples: vec![0.0, 0.5, -1.0, 2.0],
+            sample_rate: 8000,
+        };
+
+        recording.write_wav(&path).unwrap();
+
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.spec().sample_rate, 8000);
+        let samples: Vec<i16> = reader.samples().map(Result::unwrap).collect();
+        assert_eq!(samples, [0, i16::MAX / 2, -i16::MAX, i16::MAX]);
+    }
+}
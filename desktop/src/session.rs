@@ -1,11 +1,12 @@
 use crate::grade_level::GradeLevel;
+use crate::word_challenge::WordAttempt;
 use chrono::Local;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GameMode {
     Discovery,
     Challenge,
@@ -14,9 +15,13 @@ pub enum GameMode {
 /// Represents a typing session that gets saved to disk
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Session {
+    #[serde(default)]
     pub timestamp: String,
+    #[serde(default)]
     pub typed_text: String,
+    #[serde(default)]
     pub discovered_words: Vec<String>,
+    #[serde(default)]
     pub duration_seconds: Option<u64>,
     #[serde(default)]
     pub game_mode: Option<GameMode>,
@@ -24,6 +29,8 @@ pub struct Session {
     pub grade_level: Option<GradeLevel>,
     #[serde(default)]
     pub score: Option<usize>,
+    #[serde(default)]
+    pub attempts: Vec<WordAttempt>,
 }
 
 impl Session {
@@ -37,11 +44,16 @@ impl Session {
             game_mode: Some(GameMode::Discovery),
             grade_level: None,
             score: None,
+            attempts: Vec::new(),
         }
     }
 
-    /// Creates a new challenge mode session
-    pub fn new_challenge(grade_level: GradeLevel, score: usize, _words_completed: usize) -> Self {
+    /// Creates a new challenge mode session with the per-word attempt log
+    pub fn new_challenge(
+        grade_level: GradeLevel,
+        score: usize,
+        attempts: Vec<WordAttempt>,
+    ) -> Self {
         Session {
             timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
             typed_text: String::new(),
@@ -50,14 +62,12 @@ impl Session {
             game_mode: Some(GameMode::Challenge),
             grade_level: Some(grade_level),
             score: Some(score),
+            attempts,
         }
     }
 
-    /// Saves the session to disk in the appropriate directory
-    pub fn save(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
-        // Get the sessions directory
-        let sessions_dir = get_sessions_directory()?;
-
+    /// Saves the session to disk under the given sessions directory
+    pub fn save(&self, sessions_dir: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
         // Create date-based subdirectory
         let date = Local::now();
         let date_dir = sessions_dir.join(date.format("%Y-%m-%d").to_string());
@@ -80,16 +90,61 @@ impl Session {
     }
 }
 
-/// Gets the sessions directory, creating it if it doesn't exist
-fn get_sessions_directory() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    // Use Documents directory for user data
-    let documents_dir = dirs::document_dir().ok_or("Could not find Documents directory")?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_challenge::ChallengeMode;
+
+    #[test]
+    fn sessions_saved_before_attempt_logs_still_load() {
+        let json = r#"{
+            "timestamp": "2024-05-01 09:30:00",
+            "typed_text": "",
+            "discovered_words": [],
+            "duration_seconds": null,
+            "game_mode": "Challenge",
+            "grade_level": "Kindergarten",
+            "score": 7
+        }"#;
+
+        let session: Session = serde_json::from_str(json).unwrap();
 
-    let app_dir = documents_dir.join("WordsWithToddlers");
-    let sessions_dir = app_dir.join("sessions");
+        assert_eq!(session.game_mode, Some(GameMode::Challenge));
+        assert_eq!(session.grade_level, Some(GradeLevel::Kindergarten));
+        assert_eq!(session.score, Some(7));
+        assert!(session.attempts.is_empty());
+    }
+
+    #[test]
+    fn attempts_survive_saving_and_loading() {
+        let attempt = WordAttempt {
+            target_word: "cat".to_string(),
+            typed_text: "kat".to_string(),
+            correct: false,
+            wrong_attempts: 2,
+            revealed: true,
+            mode: ChallengeMode::Audio,
+            grade_level: GradeLevel::First,
+            timestamp: "2024-05-01 09:31:12".to_string(),
+            time_to_answer_ms: 4200,
+        };
+        let session = Session::new_challenge(GradeLevel::First, 3, vec![attempt]);
+        let dir = tempfile::tempdir().unwrap();
 
-    // Create directories if they don't exist
-    fs::create_dir_all(&sessions_dir)?;
+        let path = session.save(dir.path()).unwrap();
+        let loaded: Session = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
 
-    Ok(sessions_dir)
+        let [attempt] = loaded.attempts.as_slice() else {
+            panic!("expected one attempt, got {:?}", loaded.attempts);
+        };
+        assert_eq!(attempt.target_word, "cat");
+        assert_eq!(attempt.typed_text, "kat");
+        assert!(!attempt.correct);
+        assert_eq!(attempt.wrong_attempts, 2);
+        assert!(attempt.revealed);
+        assert_eq!(attempt.mode, ChallengeMode::Audio);
+        assert_eq!(attempt.grade_level, GradeLevel::First);
+        assert_eq!(attempt.timestamp, "2024-05-01 09:31:12");
+        assert_eq!(attempt.time_to_answer_ms, 4200);
+    }
 }
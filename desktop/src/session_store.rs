@@ -0,0 +1,395 @@
+use crate::grade_level::GradeLevel;
+use crate::profile::Profile;
+use crate::session::{GameMode, Session};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A session read back from disk, along with when it was recorded
+#[derive(Debug, Clone)]
+pub struct StoredSession {
+    pub started_at: NaiveDateTime,
+    pub session: Session,
+}
+
+impl StoredSession {
+    /// Returns the calendar day the session was recorded on
+    pub fn date(&self) -> NaiveDate {
+        self.started_at.date()
+    }
+}
+
+/// Criteria for selecting saved sessions; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct SessionFilter {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub game_mode: Option<GameMode>,
+    pub grade_level: Option<GradeLevel>,
+}
+
+impl SessionFilter {
+    /// Creates a filter that matches every session
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks whether a stored session satisfies every criterion
+    pub fn matches(&self, stored: &StoredSession) -> bool {
+        let date = stored.date();
+        if self.from.is_some_and(|from| date < from) {
+            return false;
+        }
+        if self.to.is_some_and(|to| date > to) {
+            return false;
+        }
+        if let Some(mode) = self.game_mode {
+            if stored.session.game_mode != Some(mode) {
+                return false;
+            }
+        }
+        if let Some(grade) = self.grade_level {
+            let played_grade = stored.session.grade_level == Some(grade)
+                || stored
+                    .session
+                    .attempts
+                    .iter()
+                    .any(|a| a.grade_level == grade);
+            if !played_grade {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Per-word totals gathered from challenge attempt logs
+#[derive(Debug, Clone, Default)]
+pub struct WordStats {
+    pub attempts: usize,
+    pub correct: usize,
+    pub incorrect: usize,
+    pub revealed: usize,
+    /// Grade level the word was most recently attempted at
+    pub grade_level: Option<GradeLevel>,
+}
+
+impl WordStats {
+    /// A word counts as mastered once it has been typed correctly at least
+    /// twice with 80% or better accuracy
+    pub fn is_mastered(&self) -> bool {
+        self.correct >= 2 && self.correct as f32 / self.attempts as f32 >= 0.8
+    }
+}
+
+/// Aggregate statistics over a set of saved sessions
+#[derive(Debug, Clone, Default)]
+pub struct SessionSummary {
+    pub session_count: usize,
+    pub total_duration_seconds: u64,
+    pub discovered_words: usize,
+    pub attempts: usize,
+    pub correct_attempts: usize,
+    pub word_stats: HashMap<String, WordStats>,
+}
+
+impl SessionSummary {
+    /// Builds a summary from already-loaded sessions
+    pub fn from_sessions<'a>(sessions: impl IntoIterator<Item = &'a StoredSession>) -> Self {
+        let mut summary = SessionSummary::default();
+
+        for stored in sessions {
+            let session = &stored.session;
+            summary.session_count += 1;
+            summary.total_duration_seconds += session.duration_seconds.unwrap_or(0);
+            summary.discovered_words += session.discovered_words.len();
+
+            for attempt in &session.attempts {
+                summary.attempts += 1;
+                let stats = summary
+                    .word_stats
+                    .entry(attempt.target_word.to_lowercase())
+                    .or_default();
+                stats.attempts += 1;
+                stats.grade_level = Some(attempt.grade_level);
+                if attempt.correct {
+                    summary.correct_attempts += 1;
+                    stats.correct += 1;
+                } else {
+                    stats.incorrect += 1;
+                }
+                if attempt.revealed {
+                    stats.revealed += 1;
+                }
+            }
+        }
+
+        summary
+    }
+
+    /// Fraction of challenge attempts that were correct, if any were made
+    pub fn accuracy(&self) -> Option<f32> {
+        if self.attempts == 0 {
+            return None;
+        }
+        Some(self.correct_attempts as f32 / self.attempts as f32)
+    }
+
+    /// Returns up to `limit` words with the most incorrect attempts
+    pub fn most_missed(&self, limit: usize) -> Vec<(&str, &WordStats)> {
+        let mut missed: Vec<(&str, &WordStats)> = self
+            .word_stats
+            .iter()
+            .filter(|(_, stats)| stats.incorrect > 0)
+            .map(|(word, stats)| (word.as_str(), stats))
+            .collect();
+        missed.sort_by(|a, b| b.1.incorrect.cmp(&a.1.incorrect).then(a.0.cmp(b.0)));
+        missed.truncate(limit);
+        missed
+    }
+}
+
+/// Reads saved sessions back from the `<sessions>/<date>/session_*.json` layout
+pub struct SessionStore {
+    root: PathBuf,
+}
+
+impl SessionStore {
+    /// Creates a store rooted at the given sessions directory
+    pub fn new(root: PathBuf) -> Self {
+        SessionStore { root }
+    }
+
+    /// Opens the store holding the given profile's sessions
+    pub fn for_profile(profile: &Profile) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::new(profile.sessions_dir()?))
+    }
+
+    /// Loads every readable session, oldest first
+    ///
+    /// Files that cannot be read or parsed are skipped with a warning so a
+    /// single corrupt file does not hide the rest of the history.
+    pub fn load_all(&self) -> Vec<StoredSession> {
+        let mut sessions = Vec::new();
+
+        let day_dirs = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) => {
+                eprintln!("Failed to read sessions directory {:?}: {}", self.root, e);
+                return sessions;
+            }
+        };
+
+        for day_dir in day_dirs.filter_map(Result::ok) {
+            let day_path = day_dir.path();
+            if !day_path.is_dir() {
+                continue;
+            }
+            let Ok(files) = fs::read_dir(&day_path) else {
+                continue;
+            };
+            for file in files.filter_map(Result::ok) {
+                let path = file.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                match Self::load_file(&path) {
+                    Ok(stored) => sessions.push(stored),
+                    Err(e) => eprintln!("Skipping unreadable session {:?}: {}", path, e),
+                }
+            }
+        }
+
+        sessions.sort_by_key(|s| s.started_at);
+        sessions
+    }
+
+    /// Loads the sessions that match the given filter, oldest first
+    pub fn list(&self, filter: &SessionFilter) -> Vec<StoredSession> {
+        self.load_all()
+            .into_iter()
+            .filter(|s| filter.matches(s))
+            .collect()
+    }
+
+    /// Reads and parses a single session file
+    fn load_file(path: &Path) -> Result<StoredSession, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let session: Session = serde_json::from_str(&contents)?;
+
+        let started_at = NaiveDateTime::parse_from_str(&session.timestamp, "%Y-%m-%d %H:%M:%S")
+            .ok()
+            .or_else(|| Self::timestamp_from_path(path))
+            .ok_or("Session has no usable timestamp")?;
+
+        Ok(StoredSession {
+            started_at,
+            session,
+        })
+    }
+
+    /// Recovers the timestamp from `<date>/session_<H-M-S>.json` when the file lacks one
+    fn timestamp_from_path(path: &Path) -> Option<NaiveDateTime> {
+        let date_name = path.parent()?.file_name()?.to_str()?;
+        let date = NaiveDate::parse_from_str(date_name, "%Y-%m-%d").ok()?;
+        let time = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.strip_prefix("session_"))
+            .and_then(|s| NaiveTime::parse_from_str(s, "%H-%M-%S").ok())
+            .unwrap_or(NaiveTime::MIN);
+        Some(date.and_time(time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a session file under `<root>/<date>/<name>`
+    fn write_session(root: &Path, date: &str, name: &str, contents: &str) {
+        let day_dir = root.join(date);
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join(name), contents).unwrap();
+    }
+
+    fn session_json(timestamp: &str, words: &[&str]) -> String {
+        let mut session =
+            Session::new(String::new(), words.iter().map(|w| w.to_string()).collect());
+        session.timestamp = timestamp.to_string();
+        serde_json::to_string(&session).unwrap()
+    }
+
+    #[test]
+    fn skips_corrupt_files_next_to_valid_sessions() {
+        let root = tempfile::tempdir().unwrap();
+        let valid = session_json("2025-01-02 09:00:00", &["cat"]);
+        write_session(root.path(), "2025-01-02", "session_09-00-00.json", &valid);
+        write_session(
+            root.path(),
+            "2025-01-02",
+            "session_10-00-00.json",
+            &valid[..valid.len() / 2],
+        );
+        write_session(
+            root.path(),
+            "2025-01-02",
+            "session_11-00-00.json",
+            "not json at all",
+        );
+        write_session(
+            root.path(),
+            "2025-01-03",
+            "session_08-30-00.json",
+            &session_json("2025-01-03 08:30:00", &["dog", "sun"]),
+        );
+
+        let sessions = SessionStore::new(root.path().to_path_buf()).load_all();
+
+        let words: Vec<&Vec<String>> = sessions
+            .iter()
+            .map(|s| &s.session.discovered_words)
+            .collect();
+        assert_eq!(
+            words,
+            [
+                &vec!["cat".to_string()],
+                &vec!["dog".to_string(), "sun".to_string()]
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_stray_files_and_non_json() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("notes.txt"), "not a day directory").unwrap();
+        write_session(root.path(), "2025-01-02", "session_09-00-00.json.bak", "{");
+        write_session(
+            root.path(),
+            "2025-01-02",
+            "session_09-00-00.json",
+            &session_json("2025-01-02 09:00:00", &[]),
+        );
+
+        assert_eq!(
+            SessionStore::new(root.path().to_path_buf())
+                .load_all()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn falls_back_to_path_timestamp() {
+        let root = tempfile::tempdir().unwrap();
+        write_session(root.path(), "2025-01-02", "session_14-05-09.json", "{}");
+
+        let sessions = SessionStore::new(root.path().to_path_buf()).load_all();
+
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(
+            sessions[0].started_at,
+            NaiveDate::from_ymd_opt(2025, 1, 2)
+                .unwrap()
+                .and_hms_opt(14, 5, 9)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn filters_by_date_mode_and_grade() {
+        let root = tempfile::tempdir().unwrap();
+        write_session(
+            root.path(),
+            "2025-01-02",
+            "session_09-00-00.json",
+            &session_json("2025-01-02 09:00:00", &["cat"]),
+        );
+        let mut challenge = Session::new_challenge(GradeLevel::First, 3, Vec::new());
+        challenge.timestamp = "2025-01-05 09:00:00".to_string();
+        write_session(
+            root.path(),
+            "2025-01-05",
+            "session_09-00-00.json",
+            &serde_json::to_string(&challenge).unwrap(),
+        );
+        let store = SessionStore::new(root.path().to_path_buf());
+        let days = |filter: SessionFilter| -> Vec<NaiveDate> {
+            store.list(&filter).iter().map(|s| s.date()).collect()
+        };
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+
+        assert_eq!(days(SessionFilter::new()), [day(2), day(5)]);
+        assert_eq!(
+            days(SessionFilter {
+                from: Some(day(3)),
+                to: Some(day(9)),
+                ..SessionFilter::new()
+            }),
+            [day(5)]
+        );
+        assert_eq!(
+            days(SessionFilter {
+                game_mode: Some(GameMode::Discovery),
+                ..SessionFilter::new()
+            }),
+            [day(2)]
+        );
+        assert_eq!(
+            days(SessionFilter {
+                grade_level: Some(GradeLevel::Second),
+                ..SessionFilter::new()
+            }),
+            []
+        );
+    }
+
+    #[test]
+    fn missing_root_loads_nothing() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(SessionStore::new(root.path().join("missing"))
+            .load_all()
+            .is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File describing a sound pack's sounds
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// One sound listed in a pack's manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundPackEntry {
+    /// Name shown in the Settings sound picker
+    pub name: String,
+    /// Audio file name, relative to the pack directory
+    pub file: String,
+}
+
+/// Contents of a sound pack's `manifest.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SoundPackManifest {
+    pub name: String,
+    #[serde(default)]
+    pub sounds: Vec<SoundPackEntry>,
+}
+
+/// User-provided sound effects: `<data>/sound_packs/<id>/` holding a
+/// manifest and WAV, OGG or FLAC files
+#[derive(Debug, Clone)]
+pub struct SoundPack {
+    id: String,
+    dir: PathBuf,
+    manifest: SoundPackManifest,
+}
+
+impl SoundPack {
+    /// Loads the pack in the given directory
+    pub fn load(dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let id = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or("sound pack directory has no usable name")?
+            .to_string();
+        let contents = fs::read_to_string(dir.join(MANIFEST_FILE))?;
+        let manifest = serde_json::from_str(&contents)?;
+        Ok(SoundPack {
+            id,
+            dir: dir.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Loads every pack in the data directory, skipping any that can't be read
+    pub fn discover() -> Vec<SoundPack> {
+        let root = match packs_root() {
+            Ok(root) => root,
+            Err(e) => {
+                eprintln!("Sound packs unavailable: {}", e);
+                return Vec::new();
+            }
+        };
+        let Ok(entries) = fs::read_dir(&root) else {
+            return Vec::new();
+        };
+
+        let mut packs: Vec<SoundPack> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.join(MANIFEST_FILE).is_file())
+            .filter_map(|dir| match Self::load(&dir) {
+                Ok(pack) => Some(pack),
+                Err(e) => {
+                    eprintln!("Failed to load sound pack {:?}: {}", dir, e);
+                    None
+                }
+            })
+            .collect();
+        packs.sort_by(|a, b| a.id.cmp(&b.id));
+        packs
+    }
+
+    /// Returns the pack's directory name, which identifies it in the config
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the pack's name from its manifest, or its id if it has none
+    pub fn name(&self) -> &str {
+        if self.manifest.name.trim().is_empty() {
+            &self.id
+        } else {
+            &self.manifest.name
+        }
+    }
+
+    /// Returns the name and file of each playable sound in the pack
+    ///
+    /// Entries whose file is missing or in an unsupported format are skipped.
+    pub fn sounds(&self) -> impl Iterator<Item = (&str, PathBuf)> + '_ {
+        self.manifest.sounds.iter().filter_map(|entry| {
+            let path = self.dir.join(&entry.file);
+            if !crate::audio::is_supported_file(&path) || !path.is_file() {
+                eprintln!(
+                    "Skipping sound {:?} in pack {}: {:?} is missing or not WAV/OGG/FLAC",
+                    entry.name,
+                    self.name(),
+                    path
+                );
+                return None;
+            }
+            Some((entry.name.as_str(), path))
+        })
+    }
+}
+
+/// Gets the directory holding every sound pack
+fn packs_root() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(crate::paths::data_dir()?.join("sound_packs"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_pack(root: &Path, id: &str, manifest: &str) -> PathBuf {
+        let dir = root.join(id);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        dir
+    }
+
+    #[test]
+    fn manifest_lists_the_pack_sounds() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = write_pack(
+            root.path(),
+            "farm",
+            r#"{
+                "name": "Farm Animals",
+                "sounds": [
+                    {"name": "Moo", "file": "moo.wav"},
+                    {"name": "Missing", "file": "gone.wav"},
+                    {"name": "Notes", "file": "notes.txt"}
+                ]
+            }"#,
+        );
+        fs::write(dir.join("moo.wav"), b"RIFF").unwrap();
+        fs::write(dir.join("notes.txt"), b"moo").unwrap();
+
+        let pack = SoundPack::load(&dir).unwrap();
+
+        assert_eq!(pack.id(), "farm");
+        assert_eq!(pack.name(), "Farm Animals");
+        let sounds: Vec<_> = pack.sounds().collect();
+        assert_eq!(sounds, [("Moo", dir.join("moo.wav"))]);
+    }
+
+    #[test]
+    fn unnamed_packs_go_by_their_id() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = write_pack(root.path(), "birds", r#"{"name": ""}"#);
+
+        let pack = SoundPack::load(&dir).unwrap();
+
+        assert_eq!(pack.name(), "birds");
+        assert_eq!(pack.sounds().count(), 0);
+    }
+
+    #[test]
+    fn broken_manifests_are_rejected() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = write_pack(root.path(), "bad", r#"{"sounds": "#);
+
+        assert!(SoundPack::load(&dir).is_err());
+    }
+}
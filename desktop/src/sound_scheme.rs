@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Something that happens in the app that can play a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SoundEvent {
+    /// A letter typed in Discovery or a challenge
+    Keystroke,
+    /// Discovery mode found a word in what was typed
+    WordDiscovered,
+    /// A discovered word finished flying up into the discovered-words strip
+    WordLanded,
+    /// A challenge word was typed correctly
+    WordCorrect,
+    /// A challenge word was typed incorrectly
+    WordWrong,
+    LevelUp,
+    LevelDown,
+    TicTacToeWin,
+    TicTacToeDraw,
+    /// Moving between screens
+    ScreenChange,
+}
+
+impl SoundEvent {
+    /// Returns the label shown on the settings button
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SoundEvent::Keystroke => "Keys",
+            SoundEvent::WordDiscovered => "Word Found",
+            SoundEvent::WordLanded => "Word Lands",
+            SoundEvent::WordCorrect => "Correct",
+            SoundEvent::WordWrong => "Wrong",
+            SoundEvent::LevelUp => "Level Up",
+            SoundEvent::LevelDown => "Level Down",
+            SoundEvent::TicTacToeWin => "Game Won",
+            SoundEvent::TicTacToeDraw => "Game Draw",
+            SoundEvent::ScreenChange => "Screens",
+        }
+    }
+
+    /// Checks whether background music should duck under this event's sound
+    pub fn is_celebration(&self) -> bool {
+        matches!(
+            self,
+            SoundEvent::WordCorrect | SoundEvent::LevelUp | SoundEvent::TicTacToeWin
+        )
+    }
+
+    /// Returns the sound an event plays until the user picks another
+    fn default_sound(&self) -> Option<&'static str> {
+        match self {
+            SoundEvent::WordDiscovered | SoundEvent::WordCorrect => Some("Swoosh"),
+            SoundEvent::LevelUp => Some("Tri-Tone"),
+            SoundEvent::TicTacToeWin => Some("Chime"),
+            SoundEvent::TicTacToeDraw => Some("Ding"),
+            SoundEvent::Keystroke
+            | SoundEvent::WordLanded
+            | SoundEvent::WordWrong
+            | SoundEvent::LevelDown
+            | SoundEvent::ScreenChange => None,
+        }
+    }
+
+    /// Returns all events, in the order shown in Settings
+    pub fn all() -> &'static [SoundEvent] {
+        &[
+            SoundEvent::Keystroke,
+            SoundEvent::WordDiscovered,
+            SoundEvent::WordLanded,
+            SoundEvent::WordCorrect,
+            SoundEvent::WordWrong,
+            SoundEvent::LevelUp,
+            SoundEvent::LevelDown,
+            SoundEvent::TicTacToeWin,
+            SoundEvent::TicTacToeDraw,
+            SoundEvent::ScreenChange,
+        ]
+    }
+}
+
+/// Which sound each event plays
+///
+/// Stored as a map from event to sound id (see `SoundLibrary`), with `null`
+/// meaning silent. Events missing from the map use their default sound.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SoundScheme {
+    sounds: BTreeMap<SoundEvent, Option<String>>,
+}
+
+impl SoundScheme {
+    /// Gets the id of the sound for an event, or `None` if it is silent
+    pub fn sound_for(&self, event: SoundEvent) -> Option<&str> {
+        match self.sounds.get(&event) {
+            Some(choice) => choice.as_deref(),
+            None => event.default_sound(),
+        }
+    }
+
+    /// Sets the sound for an event; `None` silences it
+    pub fn set(&mut self, event: SoundEvent, sound: Option<String>) {
+        self.sounds.insert(event, sound);
+    }
+}
@@ -0,0 +1,205 @@
+use chrono::{Days, NaiveDate};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Days until a word in each Leitner box is due again
+const BOX_INTERVALS_DAYS: [u64; 6] = [0, 1, 2, 4, 8, 16];
+
+/// The last box; words here are considered mastered
+pub const MASTERED_BOX: usize = BOX_INTERVALS_DAYS.len() - 1;
+
+/// Review history for a single word
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCard {
+    pub box_index: usize,
+    /// Date the word is next due, formatted as `%Y-%m-%d`
+    pub due: String,
+    #[serde(default)]
+    pub misses: usize,
+}
+
+impl ReviewCard {
+    fn due_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.due, "%Y-%m-%d").ok()
+    }
+
+    fn is_due(&self, today: NaiveDate) -> bool {
+        self.due_date().is_none_or(|due| due <= today)
+    }
+}
+
+/// Per-child Leitner-box schedule used to pick challenge words
+///
+/// Words start in box 0. A correct first try moves a word up a box, which
+/// pushes its next review further out; any miss sends it back to box 0 so it
+/// comes up again soon.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewSchedule {
+    #[serde(default)]
+    cards: HashMap<String, ReviewCard>,
+}
+
+impl ReviewSchedule {
+    /// Records the outcome of an attempt at a word made on `today`
+    pub fn record(&mut self, word: &str, correct: bool, first_try: bool, today: NaiveDate) {
+        let card = self
+            .cards
+            .entry(word.to_lowercase())
+            .or_insert_with(|| ReviewCard {
+                box_index: 0,
+                due: String::new(),
+                misses: 0,
+            });
+
+        if !correct {
+            card.box_index = 0;
+            card.misses += 1;
+        } else if first_try {
+            card.box_index = (card.box_index + 1).min(MASTERED_BOX);
+        } else {
+            // Got there in the end - review again tomorrow
+            card.box_index = 1;
+        }
+
+        let due = today
+            .checked_add_days(Days::new(BOX_INTERVALS_DAYS[card.box_index]))
+            .unwrap_or(today);
+        card.due = due.format("%Y-%m-%d").to_string();
+    }
+
+    /// Checks whether a word has been reviewed before and is due again by `today`
+    pub fn is_due(&self, word: &str, today: NaiveDate) -> bool {
+        self.cards
+            .get(&word.to_lowercase())
+            .is_some_and(|card| card.is_due(today))
+    }
+
+    /// Relative weight for choosing a word; higher means more likely to come up
+    fn weight(&self, word: &str, today: NaiveDate) -> f64 {
+        match self.cards.get(word) {
+            None => 2.0,
+            Some(card) => match (card.is_due(today), card.box_index) {
+                (true, 0) => 8.0,
+                (true, MASTERED_BOX) => 1.0,
+                (true, _) => 4.0,
+                (false, MASTERED_BOX) => 0.1,
+                (false, _) => 0.5,
+            },
+        }
+    }
+
+    /// Picks the next word from the candidates, favoring words missed or due
+    /// by `today`
+    pub fn choose<'a, R: Rng>(
+        &self,
+        candidates: &[&'a String],
+        today: NaiveDate,
+        rng: &mut R,
+    ) -> Option<&'a String> {
+        let weights: Vec<f64> = candidates
+            .iter()
+            .map(|word| self.weight(&word.to_lowercase(), today))
+            .collect();
+
+        let index = WeightedIndex::new(&weights).ok()?.sample(rng);
+        candidates.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn first_try_promotes_one_box_at_a_time() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("Cat", true, true, date(1));
+        schedule.record("cat", true, true, date(2));
+
+        let card = &schedule.cards["cat"];
+        assert_eq!(card.box_index, 2);
+        assert_eq!(card.due, "2025-03-04");
+    }
+
+    #[test]
+    fn promotion_stops_at_mastered_box() {
+        let mut schedule = ReviewSchedule::default();
+        for day in 1..=10 {
+            schedule.record("cat", true, true, date(day));
+        }
+        assert_eq!(schedule.cards["cat"].box_index, MASTERED_BOX);
+    }
+
+    #[test]
+    fn miss_demotes_to_first_box_due_today() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("cat", true, true, date(1));
+        schedule.record("cat", true, true, date(2));
+        schedule.record("cat", false, false, date(4));
+
+        let card = &schedule.cards["cat"];
+        assert_eq!(card.box_index, 0);
+        assert_eq!(card.misses, 1);
+        assert!(schedule.is_due("cat", date(4)));
+    }
+
+    #[test]
+    fn correct_after_miss_is_due_tomorrow() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("cat", false, false, date(1));
+        schedule.record("cat", true, false, date(1));
+
+        assert_eq!(schedule.cards["cat"].box_index, 1);
+        assert!(!schedule.is_due("cat", date(1)));
+        assert!(schedule.is_due("cat", date(2)));
+    }
+
+    #[test]
+    fn unseen_words_are_not_due() {
+        assert!(!ReviewSchedule::default().is_due("cat", date(1)));
+    }
+
+    #[test]
+    fn weights_favor_missed_then_due_then_new_words() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("missed", false, false, date(1));
+        schedule.record("due", true, true, date(1));
+        schedule.record("later", true, true, date(1));
+        schedule.record("later", true, true, date(2));
+        let today = date(3);
+
+        let weights: Vec<f64> = ["missed", "due", "new", "later"]
+            .iter()
+            .map(|word| schedule.weight(word, today))
+            .collect();
+        assert!(
+            weights.windows(2).all(|pair| pair[0] > pair[1]),
+            "{weights:?}"
+        );
+    }
+
+    #[test]
+    fn choose_prefers_due_words() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("cat", false, false, date(1));
+        for day in 1..=5 {
+            schedule.record("dog", true, true, date(day));
+        }
+        let (cat, dog) = ("cat".to_string(), "dog".to_string());
+        let candidates = [&cat, &dog];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let cat_picks = (0..200)
+            .filter(|_| schedule.choose(&candidates, date(5), &mut rng) == Some(&cat))
+            .count();
+        assert!(cat_picks > 190, "cat picked {cat_picks} times");
+    }
+}
@@ -0,0 +1,199 @@
+use super::types::{SpeechBackend, SpeechError, SpeechSettings};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rendered speech stored as `<data>/speech_cache/<backend>-<voice>-<rate>/<word>.wav`
+///
+/// Each word is rendered once per backend and voice, so replays play the
+/// saved file instead of starting the TTS engine again.
+pub struct SpeechCache {
+    root: PathBuf,
+}
+
+impl SpeechCache {
+    /// Creates a cache rooted at the given directory
+    pub fn new(root: PathBuf) -> Self {
+        SpeechCache { root }
+    }
+
+    /// Opens the cache in the data directory
+    pub fn open() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::new(crate::paths::data_dir()?.join("speech_cache")))
+    }
+
+    /// Gets where the rendering of a word with these settings is stored
+    pub fn path_for(&self, backend: &str, word: &str, settings: &SpeechSettings) -> PathBuf {
+        let voice = settings
+            .voice
+            .as_deref()
+            .map(|voice| {
+                // Piper voices are model paths; the file name is enough to tell them apart
+                Path::new(voice)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| voice.to_string())
+            })
+            .unwrap_or_else(|| "default".to_string());
+        let voice_dir = format!("{}-{}-{}", backend, slug(&voice), settings.rate);
+
+        self.root
+            .join(voice_dir)
+            .join(format!("{}.wav", slug(&word.to_lowercase())))
+    }
+
+    /// Returns the cached rendering of a word, rendering it first if needed
+    pub fn get_or_render(
+        &self,
+        backend: &dyn SpeechBackend,
+        word: &str,
+        settings: &SpeechSettings,
+    ) -> Result<PathBuf, SpeechError> {
+        let path = self.path_for(backend.name(), word, settings);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let dir = path.parent().unwrap_or(&self.root);
+        fs::create_dir_all(dir).map_err(|e| SpeechError::Failed(e.to_string()))?;
+
+        // Render next to the final file and rename, so a half-written file is never played
+        let temp_path = path.with_extension("wav.tmp");
+        let result = backend.render(word, settings, &temp_path).and_then(|()| {
+            fs::rename(&temp_path, &path).map_err(|e| SpeechError::Failed(e.to_string()))
+        });
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+
+        result.map(|()| path)
+    }
+}
+
+/// Makes a string safe to use as a file name
+fn slug(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::speech::SilentBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Writes a fake WAV for each render, failing after writing when asked to
+    struct FakeBackend {
+        fail: bool,
+        renders: AtomicUsize,
+    }
+
+    impl FakeBackend {
+        fn new(fail: bool) -> Self {
+            FakeBackend {
+                fail,
+                renders: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl SpeechBackend for FakeBackend {
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        fn render(
+            &self,
+            text: &str,
+            _settings: &SpeechSettings,
+            output: &Path,
+        ) -> Result<(), SpeechError> {
+            self.renders.fetch_add(1, Ordering::SeqCst);
+            fs::write(output, text).map_err(|e| SpeechError::Failed(e.to_string()))?;
+            if self.fail {
+                return Err(SpeechError::Failed("engine crashed".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    fn settings(voice: Option<&str>, rate: u32) -> SpeechSettings {
+        SpeechSettings {
+            voice: voice.map(str::to_string),
+            rate,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn paths_are_keyed_by_backend_voice_rate_and_word() {
+        let cache = SpeechCache::new(PathBuf::from("/cache"));
+        let path =
+            |backend, word, voice, rate| cache.path_for(backend, word, &settings(voice, rate));
+
+        assert_eq!(
+            path("espeak", "Cat", None, 150),
+            Path::new("/cache/espeak-default-150/cat.wav")
+        );
+        assert_eq!(
+            path("espeak", "ice cream", Some("en-us"), 120),
+            Path::new("/cache/espeak-en_us-120/ice_cream.wav")
+        );
+
+        let base = path("espeak", "cat", None, 150);
+        assert_ne!(path("say", "cat", None, 150), base);
+        assert_ne!(path("espeak", "dog", None, 150), base);
+        assert_ne!(path("espeak", "cat", Some("en-gb"), 150), base);
+        assert_ne!(path("espeak", "cat", None, 180), base);
+    }
+
+    #[test]
+    fn piper_voices_are_keyed_by_model_name() {
+        let cache = SpeechCache::new(PathBuf::from("/cache"));
+        let model = settings(Some("/home/me/voices/en_US-amy-medium.onnx"), 150);
+
+        assert_eq!(
+            cache.path_for("piper", "cat", &model),
+            Path::new("/cache/piper-en_US_amy_medium-150/cat.wav")
+        );
+    }
+
+    #[test]
+    fn words_are_rendered_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = SpeechCache::new(dir.path().to_path_buf());
+        let backend = FakeBackend::new(false);
+        let settings = SpeechSettings::default();
+
+        let first = cache.get_or_render(&backend, "cat", &settings).unwrap();
+        let second = cache.get_or_render(&backend, "Cat", &settings).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "cat");
+        assert_eq!(backend.renders.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn failed_renders_leave_nothing_behind() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = SpeechCache::new(dir.path().to_path_buf());
+        let settings = SpeechSettings::default();
+
+        let result = cache.get_or_render(&FakeBackend::new(true), "cat", &settings);
+
+        assert!(matches!(result, Err(SpeechError::Failed(_))));
+        let path = cache.path_for("fake", "cat", &settings);
+        let leftovers: Vec<_> = fs::read_dir(path.parent().unwrap()).unwrap().collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+    }
+
+    #[test]
+    fn backends_that_cannot_render_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = SpeechCache::new(dir.path().to_path_buf());
+
+        let result = cache.get_or_render(&SilentBackend::new(), "cat", &SpeechSettings::default());
+
+        assert!(matches!(result, Err(SpeechError::Unsupported)));
+    }
+}
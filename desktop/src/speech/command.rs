@@ -0,0 +1,54 @@
+use super::types::SpeechError;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+
+/// Finds an executable on `PATH`, like `which`
+pub fn find_program(program: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|candidate| is_executable(candidate))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file() || path.with_extension("exe").is_file()
+}
+
+/// Starts a prepared command without waiting for it, mapping failures to
+/// `SpeechError`
+pub fn spawn(command: &mut Command) -> Result<Child, SpeechError> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| SpeechError::Launch(program, e))
+}
+
+/// Runs a prepared command to completion, mapping failures to `SpeechError`
+pub fn run(command: &mut Command) -> Result<(), SpeechError> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command
+        .output()
+        .map_err(|e| SpeechError::Launch(program.clone(), e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(SpeechError::Failed(format!(
+            "{} exited with {}: {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
@@ -0,0 +1,53 @@
+use super::command;
+use super::types::{SpeechBackend, SpeechError, SpeechSettings};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+
+/// eSpeak NG (or the older eSpeak), available on most Linux distributions
+pub struct EspeakBackend {
+    program: PathBuf,
+}
+
+impl EspeakBackend {
+    /// Returns the backend if `espeak-ng` or `espeak` is installed
+    pub fn detect() -> Option<Self> {
+        command::find_program("espeak-ng")
+            .or_else(|| command::find_program("espeak"))
+            .map(|program| EspeakBackend { program })
+    }
+
+    /// Builds the base command with the voice settings applied
+    fn command(&self, settings: &SpeechSettings) -> Command {
+        let mut cmd = Command::new(&self.program);
+        cmd.arg("-s").arg(settings.rate.to_string());
+        if let Some(ref voice) = settings.voice {
+            cmd.arg("-v").arg(voice);
+        }
+        cmd
+    }
+}
+
+impl SpeechBackend for EspeakBackend {
+    fn name(&self) -> &'static str {
+        "espeak-ng"
+    }
+
+    fn speak(&self, text: &str, settings: &SpeechSettings) -> Result<Option<Child>, SpeechError> {
+        command::spawn(self.command(settings).arg("--").arg(text)).map(Some)
+    }
+
+    fn render(
+        &self,
+        text: &str,
+        settings: &SpeechSettings,
+        output: &Path,
+    ) -> Result<(), SpeechError> {
+        command::run(
+            self.command(settings)
+                .arg("-w")
+                .arg(output)
+                .arg("--")
+                .arg(text),
+        )
+    }
+}
@@ -0,0 +1,652 @@
+mod cache;
+mod command;
+mod espeak;
+mod piper;
+mod say;
+mod silent;
+mod speech_dispatcher;
+mod types;
+mod voice_pack;
+
+pub use cache::SpeechCache;
+pub use espeak::EspeakBackend;
+pub use piper::PiperBackend;
+pub use say::SayBackend;
+pub use silent::SilentBackend;
+pub use speech_dispatcher::SpeechDispatcherBackend;
+pub use types::*;
+pub use voice_pack::{VoicePack, DEFAULT_VOICE_PACK};
+
+use crate::audio::{AudioEngine, Channel, Sound};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::process::Child;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Counts renders made without the cache so none share a temporary file
+static NEXT_RENDER: AtomicUsize = AtomicUsize::new(0);
+
+/// How often the speech thread checks whether live speech has finished
+const LIVE_SPEECH_POLL: Duration = Duration::from_millis(30);
+
+/// One piece of speech in a sequence
+#[derive(Debug, Clone)]
+pub enum Utterance {
+    /// Text spoken through the voice pack or text-to-speech
+    Text(String),
+    /// A prepared recording, played as-is
+    Clip(Sound),
+}
+
+/// Work for the speech thread
+enum SpeechJob {
+    /// Speak in order, interrupting anything already playing
+    Speak(Vec<Utterance>, SpeechSettings),
+    /// Render words into the cache ahead of time
+    Prerender(Vec<String>, SpeechSettings),
+    /// Read the voice pack again, as clips were added to it
+    ReloadVoicePack,
+    /// Cut off any word being spoken live
+    StopLive,
+}
+
+/// The voice pack named in the speech settings, loaded once instead of for
+/// every word
+#[derive(Default)]
+struct LoadedVoicePack {
+    id: Option<String>,
+    pack: Option<VoicePack>,
+}
+
+impl LoadedVoicePack {
+    /// Returns the pack the settings ask for, loading it when that changes
+    fn get(&mut self, settings: &SpeechSettings) -> Option<&VoicePack> {
+        if self.id != settings.voice_pack {
+            self.id = settings.voice_pack.clone();
+            self.pack = self.id.as_deref().and_then(|id| match VoicePack::load(id) {
+                Ok(pack) => Some(pack),
+                Err(e) => {
+                    eprintln!("Voice pack {:?} unavailable: {}", id, e);
+                    None
+                }
+            });
+        }
+        self.pack.as_ref()
+    }
+}
+
+/// Speaks words through the configured voice pack or whichever speech
+/// backend is available
+///
+/// Recorded clips from the voice pack are preferred. Other words are rendered
+/// once into the speech cache and played on the `AudioEngine`'s speech
+/// channel, or spoken live by backends that can't render to a file. All work
+/// happens on one background thread, and requests to speak always jump ahead
+/// of pre-rendering.
+#[derive(Clone)]
+pub struct Speaker {
+    jobs: Sender<SpeechJob>,
+    audio: AudioEngine,
+}
+
+impl Speaker {
+    /// Creates a speaker that uses the given backend, caching renders in
+    /// `cache` when there is one and playing them through `audio`
+    pub fn new(
+        backend: Arc<dyn SpeechBackend>,
+        cache: Option<SpeechCache>,
+        audio: AudioEngine,
+    ) -> Self {
+        let (jobs, receiver) = mpsc::channel();
+
+        let worker_audio = audio.clone();
+        std::thread::spawn(move || SpeechThread::run(receiver, backend, cache, worker_audio));
+
+        Speaker { jobs, audio }
+    }
+
+    /// Picks the first installed backend, falling back to silence
+    ///
+    /// macOS `say` is preferred, then piper (only when the configured voice is
+    /// a model file), then espeak-ng and finally speech-dispatcher.
+    pub fn detect(settings: &SpeechSettings, audio: AudioEngine) -> Self {
+        let backend: Arc<dyn SpeechBackend> = if let Some(say) = SayBackend::detect() {
+            Arc::new(say)
+        } else if let Some(piper) = PiperBackend::detect(settings) {
+            Arc::new(piper)
+        } else if let Some(espeak) = EspeakBackend::detect() {
+            Arc::new(espeak)
+        } else if let Some(spd) = SpeechDispatcherBackend::detect() {
+            Arc::new(spd)
+        } else {
+            eprintln!("No text-to-speech engine found; install espeak-ng or speech-dispatcher");
+            Arc::new(SilentBackend::new())
+        };
+        eprintln!("Using {} for text-to-speech", backend.name());
+
+        let cache = match SpeechCache::open() {
+            Ok(cache) => Some(cache),
+            Err(e) => {
+                eprintln!("Speech cache unavailable, speaking live: {}", e);
+                None
+            }
+        };
+
+        Self::new(backend, cache, audio)
+    }
+
+    /// Speaks the word, cutting off any speech already playing
+    pub fn speak_async(&self, word: String, settings: SpeechSettings) {
+        self.speak_sequence(vec![Utterance::Text(word)], settings);
+    }
+
+    /// Speaks each utterance in turn, cutting off any speech already playing
+    pub fn speak_sequence(&self, utterances: Vec<Utterance>, settings: SpeechSettings) {
+        let _ = self.jobs.send(SpeechJob::Speak(utterances, settings));
+    }
+
+    /// Renders words into the cache in the background so they play instantly later
+    pub fn prerender(&self, words: Vec<String>, settings: SpeechSettings) {
+        let _ = self.jobs.send(SpeechJob::Prerender(words, settings));
+    }
+
+    /// Picks up clips recorded into the voice pack since it was loaded
+    pub fn reload_voice_pack(&self) {
+        let _ = self.jobs.send(SpeechJob::ReloadVoicePack);
+    }
+
+    /// Plays an audio file on the speech channel, cutting off anything playing
+    pub fn play_clip(&self, path: PathBuf, volume: f32) {
+        self.audio.set_volume(Channel::Speech, volume);
+        self.audio.play_speech(Sound::File(path), true);
+    }
+
+    /// Stops any speech that is playing or queued, including live speech
+    pub fn stop(&self) {
+        self.audio.stop(Channel::Speech);
+        let _ = self.jobs.send(SpeechJob::StopLive);
+    }
+}
+
+/// Speech thread state: speaks requested words and pre-renders the rest
+/// when idle
+struct SpeechThread {
+    backend: Arc<dyn SpeechBackend>,
+    cache: Option<SpeechCache>,
+    audio: AudioEngine,
+    voice_pack: LoadedVoicePack,
+    /// Words waiting to be pre-rendered
+    pending: VecDeque<(String, SpeechSettings)>,
+    /// The process speaking a word live, if any
+    live: Option<Child>,
+    /// Words waiting for live speech to finish
+    live_queue: VecDeque<(String, SpeechSettings)>,
+}
+
+impl SpeechThread {
+    fn run(
+        receiver: Receiver<SpeechJob>,
+        backend: Arc<dyn SpeechBackend>,
+        cache: Option<SpeechCache>,
+        audio: AudioEngine,
+    ) {
+        let mut thread = SpeechThread {
+            backend,
+            cache,
+            audio,
+            voice_pack: LoadedVoicePack::default(),
+            pending: VecDeque::new(),
+            live: None,
+            live_queue: VecDeque::new(),
+        };
+
+        loop {
+            // Check on live speech regularly, and only block for new work
+            // once there's nothing left to pre-render
+            let job = if thread.live.is_some() {
+                match receiver.recv_timeout(LIVE_SPEECH_POLL) {
+                    Ok(job) => Some(job),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            } else if thread.pending.is_empty() {
+                match receiver.recv() {
+                    Ok(job) => Some(job),
+                    Err(_) => break,
+                }
+            } else {
+                match receiver.try_recv() {
+                    Ok(job) => Some(job),
+                    Err(TryRecvError::Empty) => None,
+                    Err(TryRecvError::Disconnected) => break,
+                }
+            };
+
+            match job {
+                Some(job) => thread.handle(job),
+                None if thread.live.is_some() => {}
+                None => thread.prerender_next(),
+            }
+            thread.check_live_speech();
+        }
+        thread.stop_live_speech();
+    }
+
+    fn handle(&mut self, job: SpeechJob) {
+        match job {
+            SpeechJob::Speak(utterances, settings) => {
+                self.stop_live_speech();
+                self.audio.set_volume(Channel::Speech, settings.volume);
+                for (i, utterance) in utterances.into_iter().enumerate() {
+                    // The first utterance cuts off old speech; the rest queue behind it
+                    let interrupt = i == 0;
+                    match utterance {
+                        Utterance::Clip(sound) => self.audio.play_speech(sound, interrupt),
+                        Utterance::Text(text) => self.speak(text, &settings, interrupt),
+                    }
+                }
+            }
+            SpeechJob::Prerender(words, settings) => {
+                // Words with a recorded clip never need rendering
+                let pack = self.voice_pack.get(&settings);
+                let words: Vec<String> = words
+                    .into_iter()
+                    .filter(|word| !pack.is_some_and(|p| p.has_clip(word)))
+                    .collect();
+                self.pending
+                    .extend(words.into_iter().map(|word| (word, settings.clone())));
+            }
+            SpeechJob::ReloadVoicePack => self.voice_pack = LoadedVoicePack::default(),
+            SpeechJob::StopLive => self.stop_live_speech(),
+        }
+    }
+
+    /// Renders the next pending word into the cache
+    fn prerender_next(&mut self) {
+        let (Some((word, settings)), Some(cache)) = (self.pending.pop_front(), &self.cache) else {
+            self.pending.clear();
+            return;
+        };
+        if let Err(e) = cache.get_or_render(&*self.backend, &word, &settings) {
+            if matches!(e, SpeechError::Unsupported) {
+                // Nothing can be cached with this backend
+                self.pending.clear();
+            } else {
+                eprintln!("Failed to pre-render {:?}: {}", word, e);
+            }
+        }
+    }
+
+    /// Plays a word's recorded clip, or its rendering (from the cache when
+    /// there is one), or speaks it live
+    fn speak(&mut self, word: String, settings: &SpeechSettings, interrupt: bool) {
+        if let Some(path) = self
+            .voice_pack
+            .get(settings)
+            .and_then(|pack| pack.clip_for(&word))
+        {
+            self.audio.play_speech(Sound::File(path), interrupt);
+            return;
+        }
+
+        let rendered = match self.cache {
+            Some(ref cache) => cache
+                .get_or_render(&*self.backend, &word, settings)
+                .map(Sound::File),
+            None => render_to_memory(&*self.backend, &word, settings),
+        };
+        match rendered {
+            Ok(sound) => {
+                self.audio.play_speech(sound, interrupt);
+                return;
+            }
+            Err(SpeechError::Unsupported) => {}
+            Err(e) => eprintln!("Failed to render {:?}, speaking live: {}", word, e),
+        }
+
+        if interrupt {
+            self.audio.stop(Channel::Speech);
+        }
+        if self.live.is_some() {
+            self.live_queue.push_back((word, settings.clone()));
+        } else {
+            self.start_live_speech(&word, settings);
+        }
+    }
+
+    /// Starts speaking a word live, ducking the music until it finishes
+    fn start_live_speech(&mut self, word: &str, settings: &SpeechSettings) {
+        match self.backend.speak(word, settings) {
+            Ok(Some(process)) => {
+                self.audio.duck_music(true);
+                self.live = Some(process);
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!(
+                "Failed to speak {:?} with {}: {}",
+                word,
+                self.backend.name(),
+                e
+            ),
+        }
+    }
+
+    /// Moves on to the next queued word once live speech has finished
+    fn check_live_speech(&mut self) {
+        let Some(ref mut process) = self.live else {
+            return;
+        };
+        match process.try_wait() {
+            Ok(None) => return,
+            Ok(Some(status)) if !status.success() => {
+                eprintln!("{} exited with {}", self.backend.name(), status);
+            }
+            Ok(Some(_)) => {}
+            Err(e) => eprintln!("Lost track of {}: {}", self.backend.name(), e),
+        }
+        self.live = None;
+
+        while let Some((word, settings)) = self.live_queue.pop_front() {
+            self.start_live_speech(&word, &settings);
+            if self.live.is_some() {
+                return;
+            }
+        }
+        self.audio.duck_music(false);
+    }
+
+    /// Cuts off live speech and forgets the words queued behind it
+    fn stop_live_speech(&mut self) {
+        self.live_queue.clear();
+        if let Some(mut process) = self.live.take() {
+            let _ = process.kill();
+            let _ = process.wait();
+            self.backend.cancel();
+            self.audio.duck_music(false);
+        }
+    }
+}
+
+/// Renders a word through a temporary file and keeps the audio in memory,
+/// for when there is no speech cache
+fn render_to_memory(
+    backend: &dyn SpeechBackend,
+    word: &str,
+    settings: &SpeechSettings,
+) -> Result<Sound, SpeechError> {
+    let path = std::env::temp_dir().join(format!(
+        "words-with-toddlers-speech-{}-{}.wav",
+        std::process::id(),
+        NEXT_RENDER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = backend.render(word, settings, &path).and_then(|()| {
+        std::fs::read(&path).map_err(|e| SpeechError::Failed(format!("no audio rendered: {}", e)))
+    });
+    let _ = std::fs::remove_file(&path);
+    result.map(|bytes| Sound::Buffer(bytes.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::AudioCommand;
+    use std::time::{Duration, Instant};
+
+    /// Speech-channel commands, simplified for comparing
+    #[derive(Debug, PartialEq)]
+    enum Played {
+        Stop,
+        Play(Sound),
+        Queue(Sound),
+    }
+
+    fn silent_speaker(
+        cache: Option<SpeechCache>,
+    ) -> (Speaker, Arc<SilentBackend>, Receiver<AudioCommand>) {
+        let backend = Arc::new(SilentBackend::new());
+        let (audio, commands) = AudioEngine::detached();
+        let speaker = Speaker::new(backend.clone(), cache, audio);
+        (speaker, backend, commands)
+    }
+
+    /// Waits for the speech thread to speak `count` words live
+    fn wait_for_spoken(backend: &SilentBackend, count: usize) -> Vec<String> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let spoken = backend.spoken();
+            if spoken.len() >= count {
+                return spoken;
+            }
+            assert!(Instant::now() < deadline, "only spoke {:?}", spoken);
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    fn played(commands: &Receiver<AudioCommand>) -> Vec<Played> {
+        commands
+            .try_iter()
+            .filter_map(|command| match command {
+                AudioCommand::Stop(Channel::Speech) => Some(Played::Stop),
+                AudioCommand::PlaySpeech {
+                    sound,
+                    interrupt: true,
+                } => Some(Played::Play(sound)),
+                AudioCommand::PlaySpeech {
+                    sound,
+                    interrupt: false,
+                } => Some(Played::Queue(sound)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sequence_interrupts_once_then_queues() {
+        let (speaker, backend, commands) = silent_speaker(None);
+
+        speaker.speak_sequence(
+            vec![
+                Utterance::Clip(Sound::File("c.wav".into())),
+                Utterance::Text("cat".to_string()),
+                Utterance::Clip(Sound::File("at.wav".into())),
+            ],
+            SpeechSettings::default(),
+        );
+        speaker.speak_async("done".to_string(), SpeechSettings::default());
+
+        assert_eq!(wait_for_spoken(&backend, 2), ["cat", "done"]);
+        assert_eq!(
+            played(&commands),
+            [
+                Played::Play(Sound::File("c.wav".into())),
+                Played::Queue(Sound::File("at.wav".into())),
+                Played::Stop
+            ]
+        );
+    }
+
+    #[test]
+    fn each_request_cuts_off_the_last() {
+        let (speaker, backend, commands) = silent_speaker(None);
+
+        for word in ["one", "two", "three"] {
+            speaker.speak_async(word.to_string(), SpeechSettings::default());
+        }
+
+        assert_eq!(wait_for_spoken(&backend, 3), ["one", "two", "three"]);
+        assert_eq!(
+            played(&commands),
+            [Played::Stop, Played::Stop, Played::Stop]
+        );
+    }
+
+    #[test]
+    fn stop_clears_the_speech_channel() {
+        let (speaker, _backend, commands) = silent_speaker(None);
+        speaker.stop();
+        assert_eq!(played(&commands), [Played::Stop]);
+    }
+
+    #[test]
+    fn prerendering_gives_up_when_backend_cannot_render() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = SpeechCache::new(cache_dir.path().to_path_buf());
+        let (speaker, backend, _commands) = silent_speaker(Some(cache));
+
+        speaker.prerender(
+            vec!["cat".to_string(), "dog".to_string()],
+            SpeechSettings::default(),
+        );
+        speaker.speak_async("sun".to_string(), SpeechSettings::default());
+
+        assert_eq!(wait_for_spoken(&backend, 1), ["sun"]);
+        let rendered = walk(cache_dir.path());
+        assert!(rendered.is_empty(), "{:?}", rendered);
+    }
+
+    /// Renders each word as a "WAV" holding just its text
+    struct TextRenderer;
+
+    impl SpeechBackend for TextRenderer {
+        fn name(&self) -> &'static str {
+            "text"
+        }
+
+        fn render(
+            &self,
+            text: &str,
+            _settings: &SpeechSettings,
+            output: &std::path::Path,
+        ) -> Result<(), SpeechError> {
+            std::fs::write(output, text).map_err(|e| SpeechError::Failed(e.to_string()))
+        }
+    }
+
+    #[test]
+    fn renderings_play_through_the_audio_engine_without_a_cache() {
+        let (audio, commands) = AudioEngine::detached();
+        let speaker = Speaker::new(Arc::new(TextRenderer), None, audio);
+
+        speaker.speak_async("cat".to_string(), SpeechSettings::default());
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let sound = loop {
+            match commands.recv_timeout(Duration::from_millis(50)) {
+                Ok(AudioCommand::PlaySpeech {
+                    sound,
+                    interrupt: true,
+                }) => break sound,
+                _ => assert!(Instant::now() < deadline, "nothing was played"),
+            }
+        };
+        let Sound::Buffer(bytes) = sound else {
+            panic!("expected rendered audio, got {:?}", sound);
+        };
+        assert_eq!(&bytes[..], b"cat");
+    }
+
+    /// Speaks live by sleeping for as many seconds as the text says
+    #[cfg(unix)]
+    #[derive(Default)]
+    struct Sleeper {
+        started: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[cfg(unix)]
+    impl SpeechBackend for Sleeper {
+        fn name(&self) -> &'static str {
+            "sleep"
+        }
+
+        fn speak(
+            &self,
+            text: &str,
+            _settings: &SpeechSettings,
+        ) -> Result<Option<Child>, SpeechError> {
+            self.started.lock().unwrap().push(text.to_string());
+            command::spawn(std::process::Command::new("sleep").arg(text)).map(Some)
+        }
+    }
+
+    /// Collects music ducking changes until the music comes back up
+    fn ducking_until_restored(commands: &Receiver<AudioCommand>) -> Vec<bool> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut ducking = Vec::new();
+        while ducking.last() != Some(&false) {
+            assert!(
+                Instant::now() < deadline,
+                "music stayed ducked: {:?}",
+                ducking
+            );
+            if let Ok(AudioCommand::DuckMusic(ducked)) =
+                commands.recv_timeout(Duration::from_millis(50))
+            {
+                ducking.push(ducked);
+            }
+        }
+        ducking
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn live_words_are_spoken_in_turn_under_ducked_music() {
+        let backend = Arc::new(Sleeper::default());
+        let (audio, commands) = AudioEngine::detached();
+        let speaker = Speaker::new(backend.clone(), None, audio);
+
+        speaker.speak_sequence(
+            vec![
+                Utterance::Text("0.1".to_string()),
+                Utterance::Text("0".to_string()),
+            ],
+            SpeechSettings::default(),
+        );
+
+        let ducking = ducking_until_restored(&commands);
+        assert_eq!(ducking.first(), Some(&true));
+        assert_eq!(*backend.started.lock().unwrap(), ["0.1", "0"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stop_cuts_off_live_speech() {
+        let backend = Arc::new(Sleeper::default());
+        let (audio, commands) = AudioEngine::detached();
+        let speaker = Speaker::new(backend.clone(), None, audio);
+
+        speaker.speak_sequence(
+            vec![
+                Utterance::Text("10".to_string()),
+                Utterance::Text("10".to_string()),
+            ],
+            SpeechSettings::default(),
+        );
+        while !matches!(commands.recv().unwrap(), AudioCommand::DuckMusic(true)) {}
+        let stopped = Instant::now();
+        speaker.stop();
+
+        assert_eq!(ducking_until_restored(&commands), [false]);
+        assert!(stopped.elapsed() < Duration::from_secs(5));
+        assert_eq!(*backend.started.lock().unwrap(), ["10"]);
+    }
+
+    /// Lists the files under a directory
+    fn walk(dir: &std::path::Path) -> Vec<PathBuf> {
+        std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .flat_map(|entry| {
+                let path = entry.path();
+                if path.is_dir() {
+                    walk(&path)
+                } else {
+                    vec![path]
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use super::command;
+use super::types::{SpeechBackend, SpeechError, SpeechSettings, DEFAULT_RATE_WPM};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Piper neural TTS; needs a `.onnx` voice model given as the configured voice
+///
+/// Piper only renders to files, which the speech thread plays.
+pub struct PiperBackend {
+    program: PathBuf,
+}
+
+impl PiperBackend {
+    /// Returns the backend if `piper` is installed and the voice is a model file
+    pub fn detect(settings: &SpeechSettings) -> Option<Self> {
+        let model = settings.voice.as_deref()?;
+        if !Path::new(model).is_file() {
+            return None;
+        }
+        command::find_program("piper").map(|program| PiperBackend { program })
+    }
+}
+
+impl SpeechBackend for PiperBackend {
+    fn name(&self) -> &'static str {
+        "piper"
+    }
+
+    fn render(
+        &self,
+        text: &str,
+        settings: &SpeechSettings,
+        output: &Path,
+    ) -> Result<(), SpeechError> {
+        let model = settings
+            .voice
+            .as_deref()
+            .ok_or_else(|| SpeechError::Failed("piper needs a voice model".to_string()))?;
+        // Piper's length scale is inverse to speed: 2.0 is half as fast
+        let length_scale = DEFAULT_RATE_WPM as f32 / settings.rate.max(1) as f32;
+        let program = self.program.to_string_lossy().into_owned();
+
+        let mut child = Command::new(&self.program)
+            .arg("--model")
+            .arg(model)
+            .arg("--length_scale")
+            .arg(length_scale.to_string())
+            .arg("--output_file")
+            .arg(output)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| SpeechError::Launch(program.clone(), e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .map_err(|e| SpeechError::Launch(program.clone(), e))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| SpeechError::Launch(program, e))?;
+        if !output.status.success() {
+            return Err(SpeechError::Failed(format!(
+                "piper exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(())
+    }
+}
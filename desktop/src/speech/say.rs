@@ -0,0 +1,56 @@
+use super::command;
+use super::types::{SpeechBackend, SpeechError, SpeechSettings};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+
+/// macOS `say`
+pub struct SayBackend {
+    program: PathBuf,
+}
+
+impl SayBackend {
+    /// Returns the backend on macOS, where `say` is always installed
+    pub fn detect() -> Option<Self> {
+        if !cfg!(target_os = "macos") {
+            return None;
+        }
+        command::find_program("say").map(|program| SayBackend { program })
+    }
+
+    /// Builds the base command with the voice settings applied
+    fn command(&self, settings: &SpeechSettings) -> Command {
+        let mut cmd = Command::new(&self.program);
+        cmd.arg("-r").arg(settings.rate.to_string());
+        if let Some(ref voice) = settings.voice {
+            cmd.arg("-v").arg(voice);
+        }
+        cmd
+    }
+}
+
+impl SpeechBackend for SayBackend {
+    fn name(&self) -> &'static str {
+        "say"
+    }
+
+    fn speak(&self, text: &str, settings: &SpeechSettings) -> Result<Option<Child>, SpeechError> {
+        command::spawn(self.command(settings).arg("--").arg(text)).map(Some)
+    }
+
+    fn render(
+        &self,
+        text: &str,
+        settings: &SpeechSettings,
+        output: &Path,
+    ) -> Result<(), SpeechError> {
+        command::run(
+            self.command(settings)
+                .arg("-o")
+                .arg(output)
+                .arg("--file-format=WAVE")
+                .arg("--data-format=LEI16@22050")
+                .arg("--")
+                .arg(text),
+        )
+    }
+}
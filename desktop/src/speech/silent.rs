@@ -0,0 +1,37 @@
+use super::types::{SpeechBackend, SpeechError, SpeechSettings};
+use std::process::Child;
+use std::sync::Mutex;
+
+/// Backend that speaks nothing and just records what it was asked to say
+///
+/// Used when no speech engine is installed, and by tests that need to check
+/// what would have been spoken.
+#[derive(Default)]
+pub struct SilentBackend {
+    spoken: Mutex<Vec<String>>,
+}
+
+impl SilentBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns everything spoken so far, oldest first
+    #[cfg(test)]
+    pub fn spoken(&self) -> Vec<String> {
+        self.spoken.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+}
+
+impl SpeechBackend for SilentBackend {
+    fn name(&self) -> &'static str {
+        "silent"
+    }
+
+    fn speak(&self, text: &str, _settings: &SpeechSettings) -> Result<Option<Child>, SpeechError> {
+        if let Ok(mut spoken) = self.spoken.lock() {
+            spoken.push(text.to_string());
+        }
+        Ok(None)
+    }
+}
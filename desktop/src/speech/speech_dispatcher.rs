@@ -0,0 +1,70 @@
+use super::command;
+use super::types::{SpeechBackend, SpeechError, SpeechSettings, DEFAULT_RATE_WPM};
+use std::path::PathBuf;
+use std::process::{Child, Command};
+
+/// speech-dispatcher through its `spd-say` client
+pub struct SpeechDispatcherBackend {
+    program: PathBuf,
+}
+
+impl SpeechDispatcherBackend {
+    /// Returns the backend if `spd-say` is installed
+    pub fn detect() -> Option<Self> {
+        command::find_program("spd-say").map(|program| SpeechDispatcherBackend { program })
+    }
+
+    /// Maps words per minute onto spd-say's -100..=100 scale, where 0 is the
+    /// module's normal speed
+    fn relative_rate(rate: u32) -> i32 {
+        let percent = (rate as f32 / DEFAULT_RATE_WPM as f32 - 1.0) * 100.0;
+        (percent.round() as i32).clamp(-100, 100)
+    }
+}
+
+impl SpeechBackend for SpeechDispatcherBackend {
+    fn name(&self) -> &'static str {
+        "speech-dispatcher"
+    }
+
+    fn speak(&self, text: &str, settings: &SpeechSettings) -> Result<Option<Child>, SpeechError> {
+        let mut cmd = Command::new(&self.program);
+        // --wait keeps spd-say running until the text has been spoken
+        cmd.arg("--wait")
+            .arg("-r")
+            .arg(Self::relative_rate(settings.rate).to_string());
+        if let Some(ref voice) = settings.voice {
+            cmd.arg("-y").arg(voice);
+        }
+        command::spawn(cmd.arg("--").arg(text)).map(Some)
+    }
+
+    fn cancel(&self) {
+        // The server keeps talking after spd-say is killed
+        if let Err(e) = command::run(Command::new(&self.program).arg("--cancel")) {
+            eprintln!("Failed to stop speech-dispatcher: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rate_is_normal_speed() {
+        assert_eq!(SpeechDispatcherBackend::relative_rate(DEFAULT_RATE_WPM), 0);
+    }
+
+    #[test]
+    fn rate_scales_around_default() {
+        assert_eq!(SpeechDispatcherBackend::relative_rate(75), -50);
+        assert_eq!(SpeechDispatcherBackend::relative_rate(225), 50);
+    }
+
+    #[test]
+    fn rate_is_clamped() {
+        assert_eq!(SpeechDispatcherBackend::relative_rate(0), -100);
+        assert_eq!(SpeechDispatcherBackend::relative_rate(1000), 100);
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Child;
+
+/// Speaking rate used when none is configured, in words per minute
+pub const DEFAULT_RATE_WPM: u32 = 150;
+
+/// Voice settings shared by every speech backend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeechSettings {
+    /// Backend-specific voice name (`say -v`, `espeak-ng -v`, `spd-say -y`),
+    /// or the path to a `.onnx` model for piper. `None` uses the system default.
+    #[serde(default)]
+    pub voice: Option<String>,
+    /// Speaking rate in words per minute
+    #[serde(default = "default_rate")]
+    pub rate: u32,
+    /// Playback volume, where 1.0 is the recorded level
+    #[serde(default = "default_volume")]
+    pub volume: f32,
+    /// Id of the recorded voice pack to use before falling back to TTS
+    #[serde(default)]
+    pub voice_pack: Option<String>,
+}
+
+fn default_rate() -> u32 {
+    DEFAULT_RATE_WPM
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+impl Default for SpeechSettings {
+    fn default() -> Self {
+        SpeechSettings {
+            voice: None,
+            rate: DEFAULT_RATE_WPM,
+            volume: default_volume(),
+            voice_pack: None,
+        }
+    }
+}
+
+/// Errors that can occur when speaking text
+#[derive(Debug)]
+pub enum SpeechError {
+    /// The backend's program could not be started
+    Launch(String, std::io::Error),
+    /// The backend ran but reported a failure
+    Failed(String),
+    /// The backend can't do this, such as rendering to a file or speaking
+    /// live
+    Unsupported,
+}
+
+impl std::fmt::Display for SpeechError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Launch(program, e) => write!(f, "Failed to run {}: {}", program, e),
+            Self::Failed(msg) => write!(f, "Speech failed: {}", msg),
+            Self::Unsupported => write!(f, "Not supported by this speech backend"),
+        }
+    }
+}
+
+impl std::error::Error for SpeechError {}
+
+/// A text-to-speech engine
+///
+/// These methods may block, so callers run them off the UI thread.
+pub trait SpeechBackend: Send + Sync {
+    /// Short name used in log messages and speech cache keys
+    fn name(&self) -> &'static str;
+
+    /// Starts speaking the text live with the given voice settings
+    ///
+    /// Returns the process that is speaking so it can be waited on or killed,
+    /// or `None` if speech already finished. Backends that can render leave
+    /// this out: their renderings are played through the audio engine instead.
+    fn speak(&self, _text: &str, _settings: &SpeechSettings) -> Result<Option<Child>, SpeechError> {
+        Err(SpeechError::Unsupported)
+    }
+
+    /// Silences live speech after its process was killed, for engines that
+    /// keep speaking without it
+    fn cancel(&self) {}
+
+    /// Renders the text to a WAV file at `output`
+    fn render(
+        &self,
+        _text: &str,
+        _settings: &SpeechSettings,
+        _output: &Path,
+    ) -> Result<(), SpeechError> {
+        Err(SpeechError::Unsupported)
+    }
+}
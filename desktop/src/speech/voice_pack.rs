@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Id of the pack the recording screen writes to when none is configured
+pub const DEFAULT_VOICE_PACK: &str = "family";
+
+/// File describing a voice pack's clips
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Contents of a voice pack's `manifest.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VoicePackManifest {
+    pub name: String,
+    /// Lowercase word to audio file name, relative to the pack directory
+    #[serde(default)]
+    pub clips: BTreeMap<String, String>,
+}
+
+/// Recorded human speech: `<data>/voice_packs/<id>/` holding a manifest and
+/// one audio file per word
+///
+/// Speech plays a word's clip from the configured pack when there is one
+/// and falls back to text-to-speech otherwise.
+#[derive(Debug, Clone)]
+pub struct VoicePack {
+    dir: PathBuf,
+    manifest: VoicePackManifest,
+}
+
+impl VoicePack {
+    /// Loads an existing pack by id
+    pub fn load(id: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::load_in(&packs_root()?, id)
+    }
+
+    /// Loads an existing pack from the given packs directory
+    fn load_in(root: &Path, id: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = root.join(id);
+        let contents = fs::read_to_string(dir.join(MANIFEST_FILE))?;
+        let manifest = serde_json::from_str(&contents)?;
+        Ok(VoicePack { dir, manifest })
+    }
+
+    /// Loads a pack by id, creating an empty one if it doesn't exist yet
+    pub fn open_or_create(id: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::open_or_create_in(&packs_root()?, id)
+    }
+
+    /// Loads a pack from the given packs directory, creating it if needed
+    pub fn open_or_create_in(root: &Path, id: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if let Ok(pack) = Self::load_in(root, id) {
+            return Ok(pack);
+        }
+
+        let dir = root.join(id);
+        fs::create_dir_all(&dir)?;
+        let pack = VoicePack {
+            dir,
+            manifest: VoicePackManifest {
+                name: id.to_string(),
+                clips: BTreeMap::new(),
+            },
+        };
+        pack.save_manifest()?;
+        Ok(pack)
+    }
+
+    /// Returns the pack's display name
+    pub fn name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    /// Returns the clip for a word, if the pack has one and the file exists
+    pub fn clip_for(&self, word: &str) -> Option<PathBuf> {
+        let file = self.manifest.clips.get(&word.to_lowercase())?;
+        let path = self.dir.join(file);
+        path.is_file().then_some(path)
+    }
+
+    /// Checks whether the pack has a clip for the word
+    pub fn has_clip(&self, word: &str) -> bool {
+        self.clip_for(word).is_some()
+    }
+
+    /// Gets where a newly recorded clip for the word should be written
+    pub fn new_clip_path(&self, word: &str) -> PathBuf {
+        self.dir.join(clip_file_name(word))
+    }
+
+    /// Registers the clip written to `new_clip_path` and saves the manifest
+    pub fn add_clip(&mut self, word: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.manifest
+            .clips
+            .insert(word.to_lowercase(), clip_file_name(word));
+        self.save_manifest()
+    }
+
+    /// Writes the manifest through a temporary file so it's never left half-written
+    fn save_manifest(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.dir.join(MANIFEST_FILE);
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(&self.manifest)?)?;
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+}
+
+/// Gets the directory holding every voice pack
+fn packs_root() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(crate::paths::data_dir()?.join("voice_packs"))
+}
+
+/// Builds a file name for a word's clip
+fn clip_file_name(word: &str) -> String {
+    let stem: String = word
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{}.wav", stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clips_survive_reloading_the_pack() {
+        let root = tempfile::tempdir().unwrap();
+        let mut pack = VoicePack::open_or_create_in(root.path(), "family").unwrap();
+        fs::write(pack.new_clip_path("Dog"), "woof").unwrap();
+        pack.add_clip("Dog").unwrap();
+
+        let reloaded = VoicePack::load_in(root.path(), "family").unwrap();
+
+        assert_eq!(reloaded.name(), "family");
+        let clip = reloaded.clip_for("dog").unwrap();
+        assert_eq!(clip, root.path().join("family").join("dog.wav"));
+        assert_eq!(fs::read_to_string(clip).unwrap(), "woof");
+        assert!(reloaded.has_clip("DOG"));
+    }
+
+    #[test]
+    fn existing_packs_are_opened_not_replaced() {
+        let root = tempfile::tempdir().unwrap();
+        let manifest = r#"{"name": "Grandma", "clips": {"cat": "cat.wav"}}"#;
+        fs::create_dir_all(root.path().join("grandma")).unwrap();
+        fs::write(root.path().join("grandma").join(MANIFEST_FILE), manifest).unwrap();
+
+        let pack = VoicePack::open_or_create_in(root.path(), "grandma").unwrap();
+
+        assert_eq!(pack.name(), "Grandma");
+        assert_eq!(pack.manifest.clips.len(), 1);
+    }
+
+    #[test]
+    fn clips_with_missing_files_are_skipped() {
+        let root = tempfile::tempdir().unwrap();
+        let mut pack = VoicePack::open_or_create_in(root.path(), "family").unwrap();
+        pack.add_clip("cat").unwrap();
+
+        assert_eq!(pack.clip_for("cat"), None);
+        assert!(!pack.has_clip("cat"));
+        assert_eq!(pack.clip_for("dog"), None);
+    }
+
+    #[test]
+    fn missing_packs_fail_to_load() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(VoicePack::load_in(root.path(), "nobody").is_err());
+    }
+
+    #[test]
+    fn clip_file_names_are_safe() {
+        assert_eq!(clip_file_name("Cat"), "cat.wav");
+        assert_eq!(clip_file_name("ice cream"), "ice_cream.wav");
+        assert_eq!(clip_file_name("../up"), "___up.wav");
+    }
+}
@@ -1,62 +1,115 @@
-/// Represents a system sound option
+use crate::audio::Sound;
+use crate::sound_pack::SoundPack;
+
+/// Represents a built-in sound option
 #[derive(Debug, Clone)]
 pub struct SystemSound {
     pub name: &'static str,
     pub display_name: &'static str,
-    pub path: &'static str,
+    pub bytes: &'static [u8],
 }
 
-/// Curated list of available wav sounds
+/// Curated list of available wav sounds, compiled into the binary
 pub const SOUNDS: &[SystemSound] = &[
     SystemSound {
         name: "Swoosh",
         display_name: "Swoosh",
-        path: "../shared/sounds/Swoosh.wav",
+        bytes: include_bytes!("../../shared/sounds/Swoosh.wav"),
     },
     SystemSound {
         name: "Swish",
         display_name: "Swish",
-        path: "../shared/sounds/Swish.wav",
+        bytes: include_bytes!("../../shared/sounds/Swish.wav"),
     },
     SystemSound {
         name: "Tri-Tone",
         display_name: "Tri-Tone",
-        path: "../shared/sounds/Tri-Tone.wav",
+        bytes: include_bytes!("../../shared/sounds/Tri-Tone.wav"),
     },
     SystemSound {
         name: "Chime",
         display_name: "Chime",
-        path: "../shared/sounds/Chime.wav",
+        bytes: include_bytes!("../../shared/sounds/Chime.wav"),
     },
     SystemSound {
         name: "Bell",
         display_name: "Bell",
-        path: "../shared/sounds/Bell.wav",
+        bytes: include_bytes!("../../shared/sounds/Bell.wav"),
     },
     SystemSound {
         name: "Ding",
         display_name: "Ding",
-        path: "../shared/sounds/Ding.wav",
+        bytes: include_bytes!("../../shared/sounds/Ding.wav"),
     },
 ];
 
-/// Typewriter sound for keystroke mode
-#[allow(dead_code)]
-pub const TYPEWRITER_SOUND: &str = "../shared/sounds/typewriter-key.wav";
+impl SystemSound {
+    /// Gets the embedded audio for this sound
+    pub fn sound(&self) -> Sound {
+        Sound::Embedded {
+            name: self.name,
+            bytes: self.bytes,
+        }
+    }
+}
 
 /// Get the default sound (Swoosh)
 pub fn default_sound() -> &'static SystemSound {
     &SOUNDS[0] // Swoosh is first
 }
 
-/// Get a sound by name
-pub fn get_sound_by_name(name: &str) -> Option<&'static SystemSound> {
-    SOUNDS.iter().find(|s| s.name == name)
+/// An entry in the Settings sound picker
+#[derive(Debug, Clone)]
+pub struct SoundChoice {
+    /// Stored in the profile; built-ins use their name, pack sounds `<pack>/<name>`
+    pub id: String,
+    pub display_name: String,
+    pub sound: Sound,
+}
+
+/// Every sound effect the user can pick: the built-ins followed by the
+/// sounds from each installed sound pack
+#[derive(Debug, Clone)]
+pub struct SoundLibrary {
+    choices: Vec<SoundChoice>,
 }
 
-/// Get the path for a sound by name, or default to Swoosh
-pub fn get_sound_path(name: &str) -> &'static str {
-    get_sound_by_name(name)
-        .map(|s| s.path)
-        .unwrap_or_else(|| default_sound().path)
+impl SoundLibrary {
+    /// Collects the built-in sounds and discovers sound packs
+    pub fn load() -> Self {
+        let mut choices: Vec<SoundChoice> = SOUNDS
+            .iter()
+            .map(|s| SoundChoice {
+                id: s.name.to_string(),
+                display_name: s.display_name.to_string(),
+                sound: s.sound(),
+            })
+            .collect();
+
+        for pack in SoundPack::discover() {
+            for (name, path) in pack.sounds() {
+                choices.push(SoundChoice {
+                    id: format!("{}/{}", pack.id(), name),
+                    display_name: format!("{} ({})", name, pack.name()),
+                    sound: Sound::File(path),
+                });
+            }
+        }
+
+        SoundLibrary { choices }
+    }
+
+    /// Returns every available sound, in picker order
+    pub fn all(&self) -> &[SoundChoice] {
+        &self.choices
+    }
+
+    /// Get the sound for an id, or default to Swoosh
+    pub fn get(&self, id: &str) -> Sound {
+        self.choices
+            .iter()
+            .find(|choice| choice.id == id)
+            .map(|choice| choice.sound.clone())
+            .unwrap_or_else(|| default_sound().sound())
+    }
 }
@@ -0,0 +1,136 @@
+/// A set of words stored as a tree of characters, with a value on each word
+///
+/// Walking the tree along a piece of text finds every word that starts there
+/// in a single pass, which is what compound-word segmentation needs.
+#[derive(Debug, Clone)]
+pub struct Trie<V> {
+    nodes: Vec<Node<V>>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    /// Child node index for each next character, sorted by character
+    children: Vec<(char, usize)>,
+    /// Set when a word ends at this node
+    value: Option<V>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Node {
+            children: Vec::new(),
+            value: None,
+        }
+    }
+
+    fn child(&self, c: char) -> Option<usize> {
+        self.children
+            .binary_search_by_key(&c, |&(key, _)| key)
+            .ok()
+            .map(|i| self.children[i].1)
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Trie {
+            nodes: vec![Node::new()],
+        }
+    }
+}
+
+impl<V> Trie<V> {
+    /// Adds a word, or returns the existing value so the caller can update it
+    pub fn entry(&mut self, word: &str, value: V) -> &mut V {
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&c, |&(key, _)| key)
+            {
+                Ok(i) => self.nodes[node].children[i].1,
+                Err(i) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::new());
+                    self.nodes[node].children.insert(i, (c, child));
+                    child
+                }
+            };
+        }
+        self.nodes[node].value.get_or_insert(value)
+    }
+
+    /// Gets the value stored for a word
+    pub fn get(&self, word: &str) -> Option<&V> {
+        let mut node = 0;
+        for c in word.chars() {
+            node = self.nodes[node].child(c)?;
+        }
+        self.nodes[node].value.as_ref()
+    }
+
+    /// Finds every word that `text` starts with, returning each word's length
+    /// in characters and its value, shortest first
+    pub fn prefixes<'a>(&'a self, text: &'a [char]) -> impl Iterator<Item = (usize, &'a V)> + 'a {
+        let mut node = Some(0);
+        text.iter()
+            .enumerate()
+            .map_while(move |(i, &c)| {
+                let next = self.nodes[node?].child(c)?;
+                node = Some(next);
+                Some((i + 1, self.nodes[next].value.as_ref()))
+            })
+            .filter_map(|(len, value)| Some((len, value?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn finds_inserted_words() {
+        let mut trie = Trie::default();
+        trie.entry("cat", 1);
+        trie.entry("car", 2);
+        assert_eq!(trie.get("cat"), Some(&1));
+        assert_eq!(trie.get("car"), Some(&2));
+        assert_eq!(trie.get("ca"), None);
+        assert_eq!(trie.get("cats"), None);
+    }
+
+    #[test]
+    fn entry_keeps_existing_value() {
+        let mut trie = Trie::default();
+        trie.entry("dog", 1);
+        assert_eq!(*trie.entry("dog", 2), 1);
+    }
+
+    #[test]
+    fn prefixes_lists_words_starting_the_text() {
+        let mut trie = Trie::default();
+        for word in ["i", "in", "into", "to"] {
+            trie.entry(word, ());
+        }
+        let text = chars("intothe");
+        let lengths: Vec<usize> = trie.prefixes(&text).map(|(len, _)| len).collect();
+        assert_eq!(lengths, vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn handles_multibyte_characters() {
+        let mut trie = Trie::default();
+        trie.entry("café", ());
+        trie.entry("日本", ());
+        assert!(trie.get("café").is_some());
+        let text = chars("日本語");
+        assert_eq!(
+            trie.prefixes(&text).map(|(len, _)| len).collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::grade_level::GradeLevel;
+use crate::recorder::Recorder;
+use crate::speech::VoicePack;
+
+/// Recordings shorter than this are treated as accidental and not saved
+const MIN_CLIP_SECS: f32 = 0.2;
+
+/// State of the parent screen for recording a voice pack, one word at a time
+pub struct VoiceRecording {
+    pub pack: VoicePack,
+    pub grade_level: GradeLevel,
+    pub words: Vec<String>,
+    pub index: usize,
+    /// Outcome of the last action, shown under the word
+    pub status: Option<String>,
+    recorder: Option<Recorder>,
+}
+
+impl VoiceRecording {
+    /// Starts at the first word of the grade that has no clip yet
+    pub fn new(pack: VoicePack, grade_level: GradeLevel, words: Vec<String>) -> Self {
+        let mut recording = VoiceRecording {
+            pack,
+            grade_level,
+            words: Vec::new(),
+            index: 0,
+            status: None,
+            recorder: None,
+        };
+        recording.set_words(grade_level, words);
+        recording
+    }
+
+    /// Switches to another grade's word list
+    pub fn set_words(&mut self, grade_level: GradeLevel, words: Vec<String>) {
+        self.cancel_recording();
+        self.index = words
+            .iter()
+            .position(|word| !self.pack.has_clip(word))
+            .unwrap_or(0);
+        self.grade_level = grade_level;
+        self.words = words;
+        self.status = None;
+    }
+
+    /// Returns the word being recorded
+    pub fn current_word(&self) -> Option<&str> {
+        self.words.get(self.index).map(String::as_str)
+    }
+
+    /// Checks whether the microphone is currently recording
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Counts the words in the current list that already have a clip
+    pub fn recorded_count(&self) -> usize {
+        self.words.iter().filter(|w| self.pack.has_clip(w)).count()
+    }
+
+    /// Starts recording the current word
+    pub fn start_recording(&mut self) {
+        match Recorder::start() {
+            Ok(recorder) => {
+                self.recorder = Some(recorder);
+                self.status = Some("Recording... say the word, then press Stop".to_string());
+            }
+            Err(e) => {
+                eprintln!("Failed to start recording: {}", e);
+                self.status = Some(format!("Couldn't use the microphone: {}", e));
+            }
+        }
+    }
+
+    /// Stops recording and saves the clip for the current word
+    ///
+    /// Returns true if a clip was saved.
+    pub fn stop_recording(&mut self) -> bool {
+        let Some(recorder) = self.recorder.take() else {
+            return false;
+        };
+        let Some(word) = self.current_word().map(str::to_string) else {
+            return false;
+        };
+
+        let recording = recorder.stop().trimmed();
+        if recording.duration_secs() < MIN_CLIP_SECS {
+            self.status = Some("Didn't hear anything - try again".to_string());
+            return false;
+        }
+
+        let path = self.pack.new_clip_path(&word);
+        match recording
+            .write_wav(&path)
+            .and_then(|()| self.pack.add_clip(&word))
+        {
+            Ok(()) => {
+                self.status = Some(format!("Saved \"{}\"", word));
+                true
+            }
+            Err(e) => {
+                eprintln!("Failed to save clip for {:?}: {}", word, e);
+                self.status = Some(format!("Couldn't save the clip: {}", e));
+                false
+            }
+        }
+    }
+
+    /// Discards an in-progress recording
+    pub fn cancel_recording(&mut self) {
+        if let Some(recorder) = self.recorder.take() {
+            recorder.stop();
+        }
+    }
+
+    /// Moves to the next word
+    pub fn next_word(&mut self) {
+        self.cancel_recording();
+        if self.index + 1 < self.words.len() {
+            self.index += 1;
+            self.status = None;
+        }
+    }
+
+    /// Moves to the previous word
+    pub fn previous_word(&mut self) {
+        self.cancel_recording();
+        if self.index > 0 {
+            self.index -= 1;
+            self.status = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    /// A pack in a temporary directory with clips for the given words
+    fn pack_with_clips(root: &tempfile::TempDir, recorded: &[&str]) -> VoicePack {
+        let mut pack = VoicePack::open_or_create_in(root.path(), "family").unwrap();
+        for word in recorded {
+            fs::write(pack.new_clip_path(word), "clip").unwrap();
+            pack.add_clip(word).unwrap();
+        }
+        pack
+    }
+
+    #[test]
+    fn starts_at_the_first_unrecorded_word() {
+        let root = tempfile::tempdir().unwrap();
+        let pack = pack_with_clips(&root, &["cat", "dog"]);
+
+        let recording =
+            VoiceRecording::new(pack, GradeLevel::PreK, words(&["cat", "dog", "sun", "hat"]));
+
+        assert_eq!(recording.current_word(), Some("sun"));
+        assert_eq!(recording.recorded_count(), 2);
+        assert!(!recording.is_recording());
+    }
+
+    #[test]
+    fn fully_recorded_lists_start_at_the_beginning() {
+        let root = tempfile::tempdir().unwrap();
+        let pack = pack_with_clips(&root, &["cat", "dog"]);
+
+        let recording = VoiceRecording::new(pack, GradeLevel::PreK, words(&["cat", "dog"]));
+
+        assert_eq!(recording.current_word(), Some("cat"));
+    }
+
+    #[test]
+    fn moving_between_words_stops_at_the_ends() {
+        let root = tempfile::tempdir().unwrap();
+        let pack = pack_with_clips(&root, &[]);
+        let mut recording = VoiceRecording::new(pack, GradeLevel::PreK, words(&["cat", "dog"]));
+
+        recording.previous_word();
+        assert_eq!(recording.current_word(), Some("cat"));
+        recording.next_word();
+        recording.next_word();
+        assert_eq!(recording.current_word(), Some("dog"));
+    }
+
+    #[test]
+    fn switching_grades_resets_the_position() {
+        let root = tempfile::tempdir().unwrap();
+        let pack = pack_with_clips(&root, &["go"]);
+        let mut recording = VoiceRecording::new(pack, GradeLevel::PreK, words(&["cat", "dog"]));
+        recording.next_word();
+
+        recording.set_words(GradeLevel::First, words(&["go", "run"]));
+
+        assert_eq!(recording.grade_level, GradeLevel::First);
+        assert_eq!(recording.current_word(), Some("run"));
+        assert_eq!(recording.recorded_count(), 1);
+    }
+
+    #[test]
+    fn stopping_without_recording_saves_nothing() {
+        let root = tempfile::tempdir().unwrap();
+        let pack = pack_with_clips(&root, &[]);
+        let mut recording = VoiceRecording::new(pack, GradeLevel::PreK, words(&["cat"]));
+
+        assert!(!recording.stop_recording());
+        assert_eq!(recording.recorded_count(), 0);
+    }
+}
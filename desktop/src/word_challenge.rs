@@ -1,15 +1,57 @@
 use crate::grade_level::GradeLevel;
 use crate::letter::Letter;
-use rand::seq::SliceRandom;
+use crate::spaced_repetition::ReviewSchedule;
+use chrono::Local;
 use rand::thread_rng;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashSet, VecDeque};
+use std::time::Instant;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ChallengeMode {
     Visual,
     Audio,
 }
 
+/// A single attempt at typing the challenge word, recorded in the session log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordAttempt {
+    pub target_word: String,
+    pub typed_text: String,
+    pub correct: bool,
+    /// Wrong attempts made on this word before this one
+    pub wrong_attempts: usize,
+    /// Whether the word was being shown as a hint (audio mode after 3 misses)
+    pub revealed: bool,
+    pub mode: ChallengeMode,
+    pub grade_level: GradeLevel,
+    pub timestamp: String,
+    /// Milliseconds between the word being presented and this attempt
+    pub time_to_answer_ms: u64,
+}
+
+/// Adaptive difficulty state carried from one challenge session to the next
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdaptiveState {
+    pub grade_level: GradeLevel,
+    #[serde(default)]
+    pub recent_attempts: VecDeque<bool>,
+    #[serde(default)]
+    pub attempts_since_level_change: usize,
+    #[serde(default)]
+    pub completed_words: HashSet<String>,
+}
+
+impl AdaptiveState {
+    /// Creates a fresh state starting at the given grade level
+    pub fn starting_at(grade_level: GradeLevel) -> Self {
+        AdaptiveState {
+            grade_level,
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WordChallenge {
     pub grade_level: GradeLevel,
@@ -17,6 +59,8 @@ pub struct WordChallenge {
     pub typed_letters: Vec<Letter>,
     pub score: usize,
     pub words_completed: usize,
+    /// Words answered correctly in a row, which makes celebrations bigger
+    pub streak: usize,
     available_words: Vec<String>,
     pub is_celebrating: bool,
     pub mode: ChallengeMode,
@@ -24,51 +68,96 @@ pub struct WordChallenge {
     attempts_since_level_change: usize,
     current_word_wrong_attempts: usize,
     completed_words: HashSet<String>,
+    review_schedule: ReviewSchedule,
+    word_started_at: Instant,
+    pub started_at: Instant,
+    pub attempts: Vec<WordAttempt>,
 }
 
 impl WordChallenge {
-    pub fn new(mode: ChallengeMode, words: Vec<String>) -> Self {
+    /// Creates a challenge that resumes from a previously saved adaptive state
+    /// and picks words according to the child's review schedule
+    pub fn new(
+        mode: ChallengeMode,
+        state: AdaptiveState,
+        review_schedule: ReviewSchedule,
+        words: Vec<String>,
+    ) -> Self {
+        let mut recent_attempts = state.recent_attempts;
+        while recent_attempts.len() > 10 {
+            recent_attempts.pop_front();
+        }
+
         let mut challenge = WordChallenge {
-            grade_level: GradeLevel::PreK,
+            grade_level: state.grade_level,
             current_word: String::new(),
             typed_letters: Vec::new(),
             score: 0,
             words_completed: 0,
+            streak: 0,
             available_words: words,
             is_celebrating: false,
             mode,
-            recent_attempts: VecDeque::with_capacity(10),
-            attempts_since_level_change: 0,
+            recent_attempts,
+            attempts_since_level_change: state.attempts_since_level_change,
             current_word_wrong_attempts: 0,
-            completed_words: HashSet::new(),
+            completed_words: state.completed_words,
+            review_schedule,
+            word_started_at: Instant::now(),
+            started_at: Instant::now(),
+            attempts: Vec::new(),
         };
         challenge.next_word();
         challenge
     }
 
+    /// Snapshot of the adaptive state to persist between sessions
+    pub fn adaptive_state(&self) -> AdaptiveState {
+        AdaptiveState {
+            grade_level: self.grade_level,
+            recent_attempts: self.recent_attempts.clone(),
+            attempts_since_level_change: self.attempts_since_level_change,
+            completed_words: self.completed_words.clone(),
+        }
+    }
+
+    /// The child's review schedule, updated with this session's attempts
+    pub fn review_schedule(&self) -> &ReviewSchedule {
+        &self.review_schedule
+    }
+
     pub fn next_word(&mut self) {
         if !self.available_words.is_empty() {
             let mut rng = thread_rng();
+            let today = Local::now().date_naive();
 
-            // Filter out completed words
+            // Filter out completed words, unless the schedule says they are
+            // due for review again
             let remaining_words: Vec<&String> = self
                 .available_words
                 .iter()
-                .filter(|w| !self.completed_words.contains(*w))
+                .filter(|w| {
+                    !self.completed_words.contains(*w) || self.review_schedule.is_due(w, today)
+                })
                 .collect();
 
             // If all words completed, clear the completed set to restart
             if remaining_words.is_empty() {
                 self.completed_words.clear();
-                if let Some(word) = self.available_words.choose(&mut rng) {
+                let all_words: Vec<&String> = self.available_words.iter().collect();
+                if let Some(word) = self.review_schedule.choose(&all_words, today, &mut rng) {
                     self.current_word = word.clone();
                 }
-            } else if let Some(word) = remaining_words.choose(&mut rng) {
-                self.current_word = (*word).clone();
+            } else if let Some(word) =
+                self.review_schedule
+                    .choose(&remaining_words, today, &mut rng)
+            {
+                self.current_word = word.clone();
             }
         }
         self.typed_letters.clear();
         self.current_word_wrong_attempts = 0;
+        self.word_started_at = Instant::now();
     }
 
     pub fn typed_text(&self) -> String {
@@ -86,15 +175,27 @@ impl WordChallenge {
     pub fn handle_correct_word(&mut self) {
         self.score += 1;
         self.words_completed += 1;
+        self.streak += 1;
         self.is_celebrating = true;
+        self.log_attempt(true);
         self.record_attempt(true);
+        self.review_schedule.record(
+            &self.current_word,
+            true,
+            self.current_word_wrong_attempts == 0,
+            Local::now().date_naive(),
+        );
 
         // Add to completed words so it won't repeat in this session
         self.completed_words.insert(self.current_word.clone());
     }
 
     pub fn handle_incorrect_word(&mut self) {
+        self.streak = 0;
+        self.log_attempt(false);
         self.record_attempt(false);
+        self.review_schedule
+            .record(&self.current_word, false, false, Local::now().date_naive());
         self.current_word_wrong_attempts += 1;
     }
 
@@ -102,6 +203,21 @@ impl WordChallenge {
         self.mode == ChallengeMode::Audio && self.current_word_wrong_attempts >= 3
     }
 
+    /// Appends the current typed text to the attempt log
+    fn log_attempt(&mut self, correct: bool) {
+        self.attempts.push(WordAttempt {
+            target_word: self.current_word.clone(),
+            typed_text: self.typed_text(),
+            correct,
+            wrong_attempts: self.current_word_wrong_attempts,
+            revealed: self.should_reveal_word(),
+            mode: self.mode,
+            grade_level: self.grade_level,
+            timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            time_to_answer_ms: self.word_started_at.elapsed().as_millis() as u64,
+        });
+    }
+
     fn record_attempt(&mut self, correct: bool) {
         self.recent_attempts.push_back(correct);
         if self.recent_attempts.len() > 10 {
@@ -169,3 +285,49 @@ impl WordChallenge {
         self.typed_letters.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge_with(
+        completed: &[&str],
+        review_schedule: ReviewSchedule,
+        words: &[&str],
+    ) -> WordChallenge {
+        let state = AdaptiveState {
+            completed_words: completed.iter().map(|w| w.to_string()).collect(),
+            ..Default::default()
+        };
+        let words = words.iter().map(|w| w.to_string()).collect();
+        WordChallenge::new(ChallengeMode::Visual, state, review_schedule, words)
+    }
+
+    #[test]
+    fn due_words_come_back_even_when_completed() {
+        let today = Local::now().date_naive();
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("cat", false, false, today);
+
+        for _ in 0..20 {
+            let challenge = challenge_with(
+                &["cat", "dog", "sun"],
+                schedule.clone(),
+                &["cat", "dog", "sun"],
+            );
+            assert_eq!(challenge.current_word, "cat");
+        }
+    }
+
+    #[test]
+    fn completed_words_that_are_not_due_are_skipped() {
+        let today = Local::now().date_naive();
+        let mut schedule = ReviewSchedule::default();
+        schedule.record("cat", true, true, today);
+
+        for _ in 0..20 {
+            let challenge = challenge_with(&["cat"], schedule.clone(), &["cat", "dog"]);
+            assert_eq!(challenge.current_word, "dog");
+        }
+    }
+}
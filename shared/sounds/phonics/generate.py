@@ -0,0 +1,379 @@
+#!/usr/bin/env python3
+"""Renders the phonics clips compiled into Discovery mode's phonics setting:
+
+    names/<letter>.wav   - the letter's name ("bee")
+    sounds/<letter>.wav  - the letter's most common sound ("buh")
+
+The clips come from a small formant synthesizer in this file rather than a
+text-to-speech engine, so they need nothing but Python 3 to rebuild and come
+out byte-for-byte the same on every machine. Re-run after changing a letter
+below; recorded clips can replace any of the files.
+"""
+
+import math
+import random
+import struct
+import wave
+from pathlib import Path
+
+SAMPLE_RATE = 22050
+# Parameters are updated every BLOCK samples (about 0.7 ms)
+BLOCK = 16
+
+# Vowel formants (F1, F2, F3) of an adult female voice, from Hillenbrand et al.
+# (1995), with diphthong end points and a schwa added
+VOWELS = {
+    "i": (437, 2761, 3372),
+    "I": (483, 2365, 3053),
+    "e": (536, 2530, 3047),
+    "E": (731, 2058, 2979),
+    "ae": (669, 2349, 2972),
+    "a": (936, 1551, 2815),
+    "o": (555, 1035, 2828),
+    "u": (459, 1105, 2735),
+    "V": (753, 1426, 2933),
+    "@": (620, 1500, 2850),
+    "ai": (880, 1400, 2820),
+    "ay_end": (420, 2600, 3150),
+    "ow_end": (470, 900, 2700),
+}
+
+# Where F1-F3 start or end next to each place of articulation
+LOCI = {
+    "labial": (250, 950, 2250),
+    "alveolar": (260, 1800, 2750),
+    "velar": (260, 2300, 2650),
+    "velar_back": (260, 1500, 2500),
+}
+
+# Frication noise: centre frequency, bandwidth and level for each consonant
+FRICATIVES = {
+    "s": (6500, 3000, 0.55),
+    "f": (6000, 9000, 0.12),
+    "ch": (3200, 2000, 0.6),
+}
+
+# Burst noise when each place of closure is released
+BURSTS = {
+    "labial": (1200, 3000, 0.25),
+    "alveolar": (4500, 3000, 0.5),
+    "velar": (2600, 1200, 0.5),
+    "velar_back": (1700, 1000, 0.5),
+}
+
+DEFAULTS = dict(
+    f1=500, f2=1500, f3=2500, b1=80, b2=110, b3=170,
+    av=0.0, ah=0.0, af=0.0, ff=5000, fb=3000,
+)
+
+
+class Utterance:
+    """A timeline of synthesis parameters, built one sound at a time
+
+    Each call moves the cursor forward and adds a keyframe; parameters are
+    interpolated linearly between keyframes.
+    """
+
+    def __init__(self):
+        self.t = 0.0
+        self.current = dict(DEFAULTS)
+        self.keys = [(0.0, dict(self.current))]
+
+    def to(self, ms, **params):
+        """Glides to the given parameters over `ms` milliseconds"""
+        self.t += ms
+        self.current = {**self.current, **params}
+        self.keys.append((self.t, dict(self.current)))
+        return self
+
+    def hold(self, ms):
+        return self.to(ms)
+
+    def set(self, **params):
+        """Jumps to the given parameters (over a millisecond)"""
+        return self.to(1, **params)
+
+    # Building blocks ----------------------------------------------------
+
+    def silence(self, ms):
+        return self.set(av=0.0, ah=0.0, af=0.0).hold(ms)
+
+    def vowel(self, name, ms, glide=40, end=None):
+        """A vowel, optionally gliding to `end` (a diphthong) over its second half"""
+        f1, f2, f3 = VOWELS[name]
+        self.to(min(glide, ms / 2), f1=f1, f2=f2, f3=f3, b1=80, b2=110, b3=170,
+                av=1.0, ah=0.03)
+        if end is None:
+            return self.hold(ms - min(glide, ms / 2))
+        f1, f2, f3 = VOWELS[end]
+        self.hold((ms - glide) * 0.4)
+        return self.to((ms - glide) * 0.6, f1=f1, f2=f2, f3=f3)
+
+    def fade(self, ms=70):
+        """Lets the voice die away at the end of a word"""
+        return self.to(ms, av=0.0, ah=0.0, af=0.0)
+
+    def stop(self, place, voiced, closure=40, medial=False):
+        """A plosive: a closure, a burst of noise and (if voiceless) aspiration
+
+        The formants of the following sound are approached from the place's
+        locus, which is most of what tells "bee", "dee" and "gee" apart.
+        """
+        f1, f2, f3 = LOCI[place]
+        # Voiced stops in the middle of a word keep a low hum going
+        self.to(15, f1=f1 if medial else 200, f2=f2, f3=f3,
+                av=0.2 if medial and voiced else 0.0, ah=0.0)
+        self.hold(closure)
+        ff, fb, level = BURSTS[place]
+        self.set(av=0.0, af=level, ff=ff, fb=fb).hold(8 if voiced else 10)
+        if voiced:
+            return self.set(af=0.0, f1=f1, f2=f2, f3=f3)
+        # Aspiration: breath through the opening mouth before the voice starts
+        return self.set(af=0.0, ah=0.5, f1=f1, f2=f2, f3=f3).hold(45)
+
+    def fricative(self, kind, ms, voiced=False, place="alveolar"):
+        ff, fb, level = FRICATIVES[kind]
+        f1, f2, f3 = LOCI[place]
+        self.to(20, af=level * (0.75 if voiced else 1.0), ff=ff, fb=fb,
+                av=0.45 if voiced else 0.0, ah=0.0, f1=f1, f2=f2, f3=f3)
+        return self.hold(ms - 35).to(15, af=0.0)
+
+    def affricate(self, voiced, ms):
+        """"ch" and "j": a closure released into "sh"-like noise"""
+        self.stop("alveolar", voiced, closure=30 if voiced else 45)
+        ff, fb, level = FRICATIVES["ch"]
+        return (
+            self.set(af=level * (0.7 if voiced else 1.0), ff=ff, fb=fb,
+                     av=0.35 if voiced else 0.0, ah=0.0)
+            .hold(ms)
+            .to(15, af=0.0)
+        )
+
+    def aspirate(self, ms, vowel):
+        """"h": breath shaped by the vowel that follows"""
+        f1, f2, f3 = VOWELS[vowel]
+        return self.set(av=0.0, ah=0.7, f1=f1, f2=f2, f3=f3).hold(ms)
+
+    def nasal(self, place, ms):
+        """A hum through the nose: one strong low resonance, little above it"""
+        f2 = {"labial": 1100, "alveolar": 1700}[place]
+        self.to(20, f1=260, f2=f2, f3=2600, b1=100, b2=350, b3=450, av=0.75, ah=0.0, af=0.0)
+        return self.hold(ms - 20)
+
+    def liquid(self, kind, ms):
+        """"l" and "r" """
+        f1, f2, f3 = {"l": (360, 1050, 2800), "r": (430, 1250, 1650)}[kind]
+        self.to(50, f1=f1, f2=f2, f3=f3, b1=90, b2=130, b3=200, av=0.85, ah=0.0, af=0.0)
+        return self.hold(ms - 50)
+
+    def glide(self, kind, ms):
+        """"y" and "w": a quick vowel-like start"""
+        f1, f2, f3 = {"y": (290, 2750, 3400), "w": (320, 750, 2300)}[kind]
+        return self.to(20, f1=f1, f2=f2, f3=f3, av=0.8, ah=0.0, af=0.0).hold(ms - 20)
+
+
+def letter_names():
+    """The name of each letter, as said in American English"""
+    u = Utterance
+    return {
+        "a": u().silence(20).vowel("e", 420, end="ay_end").fade(),
+        "b": u().silence(20).stop("labial", True).vowel("i", 380).fade(),
+        "c": u().silence(20).fricative("s", 170).vowel("i", 360).fade(),
+        "d": u().silence(20).stop("alveolar", True).vowel("i", 380).fade(),
+        "e": u().silence(20).vowel("i", 430).fade(),
+        "f": u().silence(20).vowel("E", 220).fricative("f", 200, place="labial"),
+        "g": u().silence(20).affricate(True, 50).vowel("i", 360).fade(),
+        "h": u().silence(20).vowel("e", 260, end="ay_end").affricate(False, 120),
+        "i": u().silence(20).vowel("ai", 440, glide=30, end="ay_end").fade(),
+        "j": u().silence(20).affricate(True, 50).vowel("e", 380, end="ay_end").fade(),
+        "k": u().silence(20).stop("velar", False).vowel("e", 360, end="ay_end").fade(),
+        "l": u().silence(20).vowel("E", 220).liquid("l", 200).fade(),
+        "m": u().silence(20).vowel("E", 220).nasal("labial", 230).fade(60),
+        "n": u().silence(20).vowel("E", 220).nasal("alveolar", 230).fade(60),
+        "o": u().silence(20).vowel("o", 430, end="ow_end").fade(),
+        "p": u().silence(20).stop("labial", False).vowel("i", 360).fade(),
+        "q": u().silence(20).stop("velar", False).glide("y", 70).vowel("u", 340).fade(),
+        "r": u().silence(20).vowel("a", 230).liquid("r", 220).fade(),
+        "s": u().silence(20).vowel("E", 220).fricative("s", 230),
+        "t": u().silence(20).stop("alveolar", False).vowel("i", 360).fade(),
+        "u": u().silence(20).glide("y", 90).vowel("u", 360).fade(),
+        "v": (
+            u().silence(20)
+            .fricative("f", 120, voiced=True, place="labial").vowel("i", 360).fade()
+        ),
+        "w": (
+            u().silence(20)
+            .stop("alveolar", True).vowel("V", 110)
+            .stop("labial", True, closure=45, medial=True).vowel("@", 60)
+            .liquid("l", 90).glide("y", 70).vowel("u", 300).fade()
+        ),
+        "x": u().silence(20).vowel("E", 200).stop("velar", False, closure=45).fricative("s", 200),
+        "y": u().silence(20).glide("w", 80).vowel("ai", 380, glide=40, end="ay_end").fade(),
+        "z": u().silence(20).fricative("s", 140, voiced=True).vowel("i", 360).fade(),
+    }
+
+
+def letter_sounds():
+    """The most common sound of each letter, kept short after stops so "b"
+    is "b" and not "buh-uh" """
+    u = Utterance
+    return {
+        "a": u().silence(20).vowel("ae", 380).fade(),
+        "b": u().silence(20).stop("labial", True).vowel("@", 110).fade(50),
+        "c": u().silence(20).stop("velar_back", False).vowel("@", 100).fade(50),
+        "d": u().silence(20).stop("alveolar", True).vowel("@", 110).fade(50),
+        "e": u().silence(20).vowel("E", 360).fade(),
+        "f": u().silence(20).fricative("f", 520, place="labial"),
+        "g": u().silence(20).stop("velar_back", True).vowel("@", 110).fade(50),
+        "h": u().silence(20).aspirate(110, "@").vowel("@", 100).fade(50),
+        "i": u().silence(20).vowel("I", 340).fade(),
+        "j": u().silence(20).affricate(True, 50).vowel("@", 110).fade(50),
+        "k": u().silence(20).stop("velar_back", False).vowel("@", 100).fade(50),
+        "l": u().silence(20).liquid("l", 520).fade(),
+        "m": u().silence(20).nasal("labial", 520).fade(),
+        "n": u().silence(20).nasal("alveolar", 520).fade(),
+        "o": u().silence(20).vowel("a", 380).fade(),
+        "p": u().silence(20).stop("labial", False).vowel("@", 100).fade(50),
+        "q": u().silence(20).stop("velar_back", False).glide("w", 80).vowel("@", 110).fade(50),
+        "r": u().silence(20).liquid("r", 520).fade(),
+        "s": u().silence(20).fricative("s", 520),
+        "t": u().silence(20).stop("alveolar", False).vowel("@", 100).fade(50),
+        "u": u().silence(20).vowel("V", 340).fade(),
+        "v": u().silence(20).fricative("f", 520, voiced=True, place="labial").fade(40),
+        "w": u().silence(20).glide("w", 90).vowel("@", 130).fade(50),
+        "x": u().silence(20).stop("velar_back", False, closure=30).fricative("s", 260),
+        "y": u().silence(20).glide("y", 90).vowel("@", 130).fade(50),
+        "z": u().silence(20).fricative("s", 520, voiced=True).fade(40),
+    }
+
+
+class Resonator:
+    """Klatt's two-pole digital resonator, with unity gain at 0 Hz"""
+
+    def __init__(self):
+        self.a = self.b = self.c = 0.0
+        self.y1 = self.y2 = 0.0
+
+    def tune(self, freq, bandwidth):
+        t = 1.0 / SAMPLE_RATE
+        self.c = -math.exp(-2 * math.pi * bandwidth * t)
+        self.b = 2 * math.exp(-math.pi * bandwidth * t) * math.cos(2 * math.pi * freq * t)
+        self.a = 1 - self.b - self.c
+
+    def __call__(self, x):
+        y = self.a * x + self.b * self.y1 + self.c * self.y2
+        self.y2, self.y1 = self.y1, y
+        return y
+
+
+class BandPass:
+    """Band-pass biquad with a peak gain of 1, used to colour noise"""
+
+    def __init__(self):
+        self.coeffs = (0.0, 0.0, 0.0, 0.0)
+        self.x1 = self.x2 = self.y1 = self.y2 = 0.0
+
+    def tune(self, freq, bandwidth):
+        w0 = 2 * math.pi * min(freq, SAMPLE_RATE * 0.45) / SAMPLE_RATE
+        alpha = math.sin(w0) * bandwidth / (2 * freq)
+        a0 = 1 + alpha
+        self.coeffs = (alpha / a0, -2 * math.cos(w0) / a0, (1 - alpha) / a0, -alpha / a0)
+
+    def __call__(self, x):
+        b0, a1, a2, b2 = self.coeffs
+        y = b0 * x + b2 * self.x2 - a1 * self.y1 - a2 * self.y2
+        self.x2, self.x1 = self.x1, x
+        self.y2, self.y1 = self.y1, y
+        return y
+
+
+def pitch(progress):
+    """A friendly, falling intonation, in Hz"""
+    rise = 215 + 20 * math.sin(math.pi * min(progress / 0.5, 1.0) / 2)
+    return rise - 60 * max(0.0, progress - 0.3) / 0.7
+
+
+def glottal_pulse(phase, open_quotient=0.6):
+    """Derivative of the glottal airflow over one period (KLGLOTT88)"""
+    if phase >= open_quotient:
+        return 0.0
+    x = phase / open_quotient
+    return 2 * x - 3 * x * x
+
+
+def render(utterance, seed):
+    keys = utterance.keys
+    length = int(keys[-1][0] / 1000 * SAMPLE_RATE) + 1
+    rng = random.Random(seed)
+    formants = [Resonator() for _ in range(5)]
+    formants[3].tune(3900, 250)
+    formants[4].tune(4700, 300)
+    noise_filter = BandPass()
+
+    samples = []
+    phase = 0.0
+    tilt = 0.0
+    key = 0
+    for start in range(0, length, BLOCK):
+        t = start / SAMPLE_RATE * 1000
+        while key + 2 < len(keys) and keys[key + 1][0] <= t:
+            key += 1
+        (t0, p0), (t1, p1) = keys[key], keys[key + 1]
+        mix = min(max((t - t0) / (t1 - t0), 0.0), 1.0) if t1 > t0 else 1.0
+        p = {name: p0[name] + (p1[name] - p0[name]) * mix for name in p0}
+
+        for resonator, n in zip(formants, (1, 2, 3)):
+            resonator.tune(p[f"f{n}"], p[f"b{n}"])
+        noise_filter.tune(p["ff"], p["fb"])
+        step = pitch(start / length) / SAMPLE_RATE
+
+        for _ in range(min(BLOCK, length - start)):
+            noise = rng.random() * 2 - 1
+            # Soften the pulse's top end a little, like a relaxed voice
+            tilt = 0.75 * glottal_pulse(phase) + 0.25 * tilt
+            phase = (phase + step) % 1.0
+            source = p["av"] * tilt * 0.35 + p["ah"] * noise * 0.3
+            for resonator in formants:
+                source = resonator(source)
+            frication = p["af"] * noise_filter(noise) * 1.1
+            samples.append(source + frication)
+
+    return samples
+
+
+def normalize(samples, target_rms=0.2, peak=0.9):
+    """Evens out loudness between clips without clipping any"""
+    loud = [s for s in samples if abs(s) > 1e-3] or [1.0]
+    rms = math.sqrt(sum(s * s for s in loud) / len(loud))
+    gain = target_rms / rms
+    gain = min(gain, peak / max(abs(s) for s in samples))
+    # Short fades so clips never click at either end
+    fade = int(SAMPLE_RATE * 0.005)
+    out = []
+    for i, s in enumerate(samples):
+        edge = min(i, len(samples) - 1 - i, fade) / fade
+        out.append(s * gain * edge)
+    return out
+
+
+def write_wav(path, samples):
+    with wave.open(str(path), "wb") as wav:
+        wav.setnchannels(1)
+        wav.setsampwidth(2)
+        wav.setframerate(SAMPLE_RATE)
+        wav.writeframes(b"".join(struct.pack("<h", round(s * 32767)) for s in samples))
+
+
+def main():
+    here = Path(__file__).resolve().parent
+    for folder, clips in (("names", letter_names()), ("sounds", letter_sounds())):
+        (here / folder).mkdir(exist_ok=True)
+        for letter, utterance in clips.items():
+            utterance.silence(30)
+            samples = normalize(render(utterance, seed=f"{folder}/{letter}"))
+            write_wav(here / folder / f"{letter}.wav", samples)
+    print("Wrote 26 letter names and 26 letter sounds")
+
+
+if __name__ == "__main__":
+    main()
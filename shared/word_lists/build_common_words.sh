@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+# Builds desktop/src/common_words.txt, the ranked dictionary compiled into
+# the desktop app, from the word lists in this directory. Words are grouped
+# into ranks, most common first, each starting with a "# rank N" line:
+#   ranks 1-10  the Fry instant words, one rank per hundred, which are
+#               grouped by how often they are used
+#   rank 11     Dolch words not already listed
+#
+# Words are lowercased and deduplicated; entries that aren't a single word
+# of plain letters (contractions, "Santa Claus") are left out. Bump VERSION
+# whenever the output changes, and COMMON_WORDS_VERSION in
+# desktop/src/dictionary.rs to match.
+set -euo pipefail
+
+VERSION=1
+
+cd "$(dirname "$0")"
+output=../../desktop/src/common_words.txt
+
+# Prints each listed file's words that haven't been printed yet
+words() {
+    cat "$@" \
+        | tr -d '\r' \
+        | tr '[:upper:]' '[:lower:]' \
+        | grep -E '^[a-z]+$'
+}
+
+{
+    echo "# Common English words, most common first. Generated by"
+    echo "# shared/word_lists/build_common_words.sh; do not edit by hand."
+    echo "# version $VERSION"
+    rank=0
+    for list in fry_*.txt; do
+        rank=$((rank + 1))
+        echo "# rank $rank"
+        words "$list"
+    done
+    echo "# rank $((rank + 1))"
+    words dolch_pre_primer.txt dolch_primer.txt dolch_first.txt \
+        dolch_second.txt dolch_third.txt dolch_nouns.txt
+} | awk '/^#/ || !seen[$0]++' > "$output"
+
+echo "Wrote $(grep -vc '^#' "$output") words to $output"